@@ -0,0 +1,83 @@
+use std::error::Error;
+
+use aoc_common::{Answer, Operator, ParseError, Solution, parse_lines, parse_number};
+
+#[derive(Default)]
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Equation>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_lines(input, parse_equation)?)
+    }
+
+    fn part_1(&self, equations: &Self::Input) -> impl Answer {
+        sum_satisfiable_equations(equations, &[Operator::Add, Operator::Multiply])
+    }
+
+    fn part_2(&self, equations: &Self::Input) -> impl Answer {
+        sum_satisfiable_equations(equations, &[Operator::Add, Operator::Multiply, Operator::Concatenate])
+    }
+}
+
+fn sum_satisfiable_equations(equations: &[Equation], available_operations: &[Operator]) -> i64 {
+    equations.iter()
+        .filter(|e| e.is_satisfiable(available_operations))
+        .map(|e| e.result)
+        .sum()
+}
+
+pub struct Equation {
+    result: i64,
+    operands: Vec<i64>,
+}
+
+fn parse_equation(line: &str) -> Result<Equation, ParseError> {
+    let Some((result, operands)) = line.split_once(":") else {
+        return Err(ParseError::line(line, "expected an equation like 190: 10 19"));
+    };
+    let result = parse_non_negative(line, result)?;
+    let operands: Vec<i64> = operands.split_whitespace().map(|o| parse_non_negative(line, o)).collect::<Result<_, _>>()?;
+    if operands.is_empty() {
+        return Err(ParseError::at(line, &line[line.len()..], "expected at least one operand"));
+    }
+    Ok(Equation { result, operands })
+}
+
+/// The search only works back through non-negative results, so negative
+/// numbers are rejected up front rather than never matching.
+fn parse_non_negative(line: &str, number: &str) -> Result<i64, ParseError> {
+    let value = parse_number(line, number)?;
+    if value < 0 {
+        return Err(ParseError::at(line, number, format!("expected a number of at least 0, found {value}")));
+    }
+    Ok(value)
+}
+
+impl Equation {
+    fn is_satisfiable(&self, available_operations: &[Operator]) -> bool {
+        let result = self.result;
+        let operands = &self.operands;
+        if operands.len() == 1 {
+            if operands[0] == result {
+                return true;
+            }
+            return false;
+        }
+        let (&last_operand, unused_operands) = operands.split_last().unwrap();
+        for operation in available_operations {
+            // The parser rejects negative operands, so no earlier result can be negative.
+            if let Some(remaining_result) = operation.inverse(result, last_operand).filter(|&r| r >= 0) {
+                let remaining_equation = Equation {
+                    result: remaining_result,
+                    operands: unused_operands.to_vec(),
+                };
+                if remaining_equation.is_satisfiable(available_operations) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
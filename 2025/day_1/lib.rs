@@ -0,0 +1,93 @@
+use std::error::Error;
+
+use aoc_common::{Answer, ParseError, Solution, parse_lines, parse_number};
+
+const INIT_POS: i32 = 50;
+const NUM_POS: i32 = 100;
+
+#[derive(Default)]
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<Move>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_lines(input, parse_move)?)
+    }
+
+    fn part_1(&self, moves: &Self::Input) -> impl Answer {
+        count_zeros(moves).zeros_landed_on
+    }
+
+    fn part_2(&self, moves: &Self::Input) -> impl Answer {
+        count_zeros(moves).zeros_crossed
+    }
+}
+
+pub enum Move {
+    Right(u32),
+    Left(u32),
+}
+
+fn parse_move(line: &str) -> Result<Move, ParseError> {
+    let Some(first_char) = line.chars().next() else {
+        return Err(ParseError::line(line, "expected a move like R10, found an empty line"));
+    };
+    let (direction, num) = line.split_at(first_char.len_utf8());
+    let num = parse_number(line, num)?;
+    match direction {
+        "R" => Ok(Move::Right(num)),
+        "L" => Ok(Move::Left(num)),
+        x => Err(ParseError::at(line, direction, format!("invalid direction '{x}', expected R or L"))),
+    }
+}
+
+struct ZeroCounts {
+    zeros_landed_on: i32,
+    zeros_crossed: i64,
+}
+
+fn count_zeros(moves: &[Move]) -> ZeroCounts {
+    let mut pos = INIT_POS;
+    let mut zeros_landed_on = 0;
+    let mut zeros_crossed = 0;
+    for lock_move in moves {
+        // Every full turn passes 0 once, so only the rest of the move is made.
+        let (Move::Right(distance) | Move::Left(distance)) = *lock_move;
+        zeros_crossed += i64::from(distance / NUM_POS as u32);
+        let new_pos = make_move(pos, lock_move);
+        zeros_crossed += i64::from(count_zeros_between(pos, new_pos));
+        pos = wrap_pos(new_pos);
+        if pos == 0 {
+            zeros_landed_on += 1;
+        }
+    }
+    ZeroCounts {
+        zeros_landed_on,
+        zeros_crossed
+    }
+}
+
+/// Where the dial ends up after the part of `lock_move` left over from its
+/// full turns, unwrapped, so within a turn either side of `pos`.
+fn make_move(pos: i32, lock_move: &Move) -> i32 {
+    match *lock_move {
+        Move::Right(num) => pos + (num % NUM_POS as u32) as i32,
+        Move::Left(num) => pos - (num % NUM_POS as u32) as i32,
+    }
+}
+
+fn wrap_pos(pos: i32) -> i32 {
+    let wrapped_pos = pos % NUM_POS;
+    if wrapped_pos < 0 { wrapped_pos + NUM_POS } else { wrapped_pos }
+}
+
+fn count_zeros_between(old_pos: i32, new_pos: i32) -> i32 {
+    // we know old_pos is in [0, NUM_POS)
+    let has_not_crossed_zero = new_pos < old_pos && new_pos > 0;
+    if has_not_crossed_zero {
+        return 0;
+    }
+    let crosses_zero_downward = old_pos > 0 && new_pos <= 0;
+    new_pos.abs() / NUM_POS + if crosses_zero_downward { 1 } else { 0 }
+}
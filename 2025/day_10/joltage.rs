@@ -0,0 +1,586 @@
+//! Part 2: the cheapest presses that raise every counter to exactly its
+//! joltage. Each button is a column of an equation system `A·x = b` with `x`
+//! the non-negative whole number of presses of each button, and the cost to
+//! minimize is `c·x`, which is the number of presses when every button costs 1.
+
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+
+use priority_queue::PriorityQueue;
+
+use aoc_common::{Matrix, Overflow, Rational, scale_to_integers};
+
+use crate::{Button, Infeasibility, Machine, Meter, Outcome, PressPlan};
+
+/// Solves the machine's equations exactly: row reduction leaves a few free
+/// buttons, and every assignment of those within their bounds is tried, with
+/// branches cut as soon as they cannot beat the best so far or must drive a
+/// pivot button negative. Each free button is bounded by the smallest joltage
+/// it feeds, so the search always ends. Each assignment tried is a step of
+/// the budget.
+pub fn min_presses(machine: &Machine, meter: &mut Meter) -> Result<Outcome, Overflow> {
+    let system = match ReducedSystem::new(machine)? {
+        Ok(system) => system,
+        Err(outcome) => return Ok(outcome),
+    };
+    let mut search = FreeSearch { system: &system, meter, free_values: vec![0; system.free.len()], best: None, out_of_budget: false };
+    search.visit(0, 0);
+    Ok(match search.best {
+        _ if search.out_of_budget => search.meter.exhausted(),
+        Some(plan) => Outcome::Solved(plan),
+        None => Outcome::Infeasible(Infeasibility::NoNonNegativeSolution),
+    })
+}
+
+/// `A·x = b` in reduced row echelon form, scaled to stay in whole numbers:
+/// row `r` reads `pivots[r].1 · x[pivots[r].0] + Σ coefficients[r][f] · x[free[f]] = rhs[r]`.
+struct ReducedSystem {
+    costs: Vec<i64>,
+    pivots: Vec<(usize, i64)>,
+    free: Vec<usize>,
+    /// The most times each free button can be pressed.
+    free_bounds: Vec<i64>,
+    coefficients: Vec<Vec<i64>>,
+    rhs: Vec<i64>,
+}
+
+impl ReducedSystem {
+    /// Gives the outcome instead if elimination finds an equation no presses
+    /// can satisfy. Also checks that no sum or cost the search can form
+    /// overflows, so it can use plain arithmetic.
+    fn new(machine: &Machine) -> Result<Result<Self, Outcome>, Overflow> {
+        let num_buttons = machine.buttons.len();
+        let augmented = Matrix::from_fn(machine.joltage_target.len(), num_buttons + 1, |counter, col| match machine.buttons.get(col) {
+            Some(button) => Rational::from(button.toggle_indices.contains(&counter) as i64),
+            None => Rational::from(machine.joltage_target[counter]),
+        });
+        let rref = augmented.rref()?;
+        if rref.pivot_cols.contains(&num_buttons) {
+            return inconsistency(machine).map(Err);
+        }
+        // No button is pressed more often than the largest joltage in the best plan.
+        let largest_joltage = machine.joltage_target.iter().copied().max().unwrap_or(0);
+        machine.check_costs(largest_joltage)?;
+        let costs: Vec<i64> = machine.buttons.iter().map(|button| button.cost).collect();
+        let free: Vec<usize> = rref.free_cols().into_iter().filter(|&col| col < num_buttons).collect();
+        let free_bounds: Vec<i64> = free
+            .iter()
+            .map(|&col| {
+                let fed = machine.joltage_target.iter().enumerate().filter(|(counter, _)| machine.buttons[col].toggle_indices.contains(counter));
+                fed.map(|(_, &target)| target).min().unwrap_or(0)
+            })
+            .collect();
+        let mut pivots = vec![];
+        let mut coefficients = vec![];
+        let mut rhs = vec![];
+        for (row, &col) in rref.pivot_cols.iter().enumerate() {
+            let values = scale_to_integers(rref.matrix.row(row))?;
+            let row_coefficients: Vec<i64> = free.iter().map(|&free_col| values[free_col]).collect();
+            let largest_sum = row_coefficients
+                .iter()
+                .zip(&free_bounds)
+                .try_fold(values[num_buttons].checked_abs().ok_or(Overflow)?, |sum, (coefficient, bound)| {
+                    coefficient.checked_abs().and_then(|c| c.checked_mul(*bound)).and_then(|term| sum.checked_add(term))
+                });
+            largest_sum.ok_or(Overflow)?;
+            pivots.push((col, values[col]));
+            coefficients.push(row_coefficients);
+            rhs.push(values[num_buttons]);
+        }
+        Ok(Ok(ReducedSystem { costs, pivots, free, free_bounds, coefficients, rhs }))
+    }
+}
+
+/// A combination of the counters that no button changes but the joltages
+/// make non-zero, proving them out of reach. When `A·x = b` has no solution,
+/// some `y` with `yᵀ·A = 0` has `yᵀ·b ≠ 0`, and the nullspace of `Aᵀ` holds
+/// one. Failing to find it means elimination went wrong, which is reported
+/// as a failed solve.
+fn inconsistency(machine: &Machine) -> Result<Outcome, Overflow> {
+    let target = &machine.joltage_target;
+    let transposed = Matrix::from_fn(machine.buttons.len(), target.len(), |button, counter| {
+        Rational::from(machine.buttons[button].toggle_indices.contains(&counter) as i64)
+    });
+    for multipliers in transposed.rref()?.integer_nullspace()? {
+        let total = multipliers.iter().zip(target).try_fold(0_i64, |total, (&multiplier, &joltage)| {
+            multiplier.checked_mul(joltage).and_then(|term| total.checked_add(term)).ok_or(Overflow)
+        })?;
+        if total != 0 {
+            return Ok(Outcome::Infeasible(Infeasibility::Inconsistent { multipliers, total }));
+        }
+    }
+    Ok(Outcome::Failed("elimination found the joltages inconsistent, but no combination of them shows it".to_string()))
+}
+
+struct FreeSearch<'a> {
+    system: &'a ReducedSystem,
+    meter: &'a mut Meter,
+    free_values: Vec<i64>,
+    best: Option<PressPlan>,
+    out_of_budget: bool,
+}
+
+impl FreeSearch<'_> {
+    /// Tries every value of free button `index` onwards, given the values of
+    /// the ones before it and what they cost.
+    fn visit(&mut self, index: usize, free_cost: i64) {
+        if self.out_of_budget || !self.meter.tick() {
+            self.out_of_budget = true;
+            return;
+        }
+        if self.best.as_ref().is_some_and(|best| free_cost >= best.total) || !self.pivots_can_stay_non_negative(index) {
+            return;
+        }
+        if index == self.free_values.len() {
+            self.evaluate();
+            return;
+        }
+        for value in 0..=self.system.free_bounds[index] {
+            self.free_values[index] = value;
+            self.visit(index + 1, free_cost + value * self.system.costs[self.system.free[index]]);
+        }
+        self.free_values[index] = 0;
+    }
+
+    /// Whether every pivot row still has a non-negative numerator for some
+    /// choice of the free buttons from `index` onwards.
+    fn pivots_can_stay_non_negative(&self, index: usize) -> bool {
+        let system = self.system;
+        (0..system.pivots.len()).all(|row| {
+            let coefficients = &system.coefficients[row];
+            let assigned: i64 = (0..index).map(|f| coefficients[f] * self.free_values[f]).sum();
+            let best_unassigned: i64 = (index..coefficients.len()).map(|f| (coefficients[f] * system.free_bounds[f]).min(0)).sum();
+            system.rhs[row] - assigned - best_unassigned >= 0
+        })
+    }
+
+    fn evaluate(&mut self) {
+        let system = self.system;
+        let mut presses = vec![0; system.costs.len()];
+        for (&col, &value) in system.free.iter().zip(&self.free_values) {
+            presses[col] = value;
+        }
+        for (row, &(col, pivot)) in system.pivots.iter().enumerate() {
+            let numerator = system.rhs[row] - system.coefficients[row].iter().zip(&self.free_values).map(|(c, v)| c * v).sum::<i64>();
+            if numerator < 0 || numerator % pivot != 0 {
+                return;
+            }
+            presses[col] = numerator / pivot;
+        }
+        let total = presses.iter().zip(&system.costs).map(|(count, cost)| count * cost).sum();
+        if self.best.as_ref().is_none_or(|best| total < best.total) {
+            self.best = Some(PressPlan { total, presses });
+        }
+    }
+}
+
+/// Press-by-press A* search from all-zero joltages, kept as an alternative to
+/// `min_presses`. It only branches on buttons feeding the counter closest to
+/// its joltage, since some press must feed it and presses can happen in any
+/// order, and it is guided by `LowerBound`, which never overestimates, so the
+/// first time it reaches the joltages it has done so most cheaply. States the
+/// bound shows to be dead ends are never queued.
+/// Each state expanded is a step of the budget.
+pub fn search_min_presses(machine: &Machine, meter: &mut Meter) -> (Outcome, SearchStats) {
+    let target = &machine.joltage_target;
+    let mut stats = SearchStats::default();
+    // A state never presses a button more often than the largest joltage, or it
+    // would overshoot.
+    if let Err(overflow) = machine.check_costs(target.iter().copied().max().unwrap_or(0)) {
+        return (Outcome::Failed(overflow.to_string()), stats);
+    }
+    let lower_bound = LowerBound::new(machine);
+    let mut queue = PriorityQueue::new();
+    // The cheapest way to each state so far, and the last button pressed to get there.
+    let mut cheapest = HashMap::new();
+    let init_state = vec![0; target.len()];
+    if let Some(estimate) = lower_bound.estimate(target, &init_state) {
+        queue.push(init_state.clone(), (Reverse(estimate), 0));
+    }
+    cheapest.insert(init_state, (0, None));
+    while let Some((curr_state, (_, curr_cost))) = queue.pop() {
+        stats.expanded += 1;
+        if curr_state == *target {
+            return (Outcome::Solved(retrace_presses(machine, &cheapest, curr_state)), stats);
+        }
+        if !meter.tick() {
+            return (meter.exhausted(), stats);
+        }
+        let closest_unfulfilled_target_index = target
+            .iter()
+            .enumerate()
+            .filter(|(i, target_v)| **target_v > curr_state[*i])
+            .min_by_key(|(i, target_v)| **target_v - curr_state[*i])
+            .map(|(i, _)| i);
+        // Every counter is at or past its joltage but they are not all at it,
+        // and presses only raise counters, so this state can never get there.
+        let Some(closest_unfulfilled_target_index) = closest_unfulfilled_target_index else {
+            stats.overshoots += 1;
+            continue;
+        };
+        for (index, button) in machine.buttons.iter().enumerate() {
+            if button.toggle_indices.contains(&closest_unfulfilled_target_index) {
+                let new_joltages = press_button_on_joltages(&curr_state, button);
+                let new_cost = curr_cost + button.cost;
+                if !could_be_valid_joltage_option(&new_joltages, target) {
+                    stats.overshoots += 1;
+                } else if cheapest.get(&new_joltages).is_some_and(|&(cost, _)| cost <= new_cost) {
+                    stats.revisits += 1;
+                } else if let Some(estimate) = lower_bound.estimate(target, &new_joltages) {
+                    let estimate = new_cost.saturating_add(estimate);
+                    cheapest.insert(new_joltages.clone(), (new_cost, Some(index)));
+                    queue.push(new_joltages, (Reverse(estimate), new_cost));
+                    stats.queued += 1;
+                } else {
+                    stats.dead_ends += 1;
+                }
+            }
+        }
+    }
+    (Outcome::Infeasible(Infeasibility::Unreachable { states: cheapest.len() as u64 }), stats)
+}
+
+/// Follows the last press into each state back from `state` to all zeros.
+fn retrace_presses(machine: &Machine, cheapest: &HashMap<Vec<i64>, (i64, Option<usize>)>, mut state: Vec<i64>) -> PressPlan {
+    let mut presses = vec![0; machine.buttons.len()];
+    while let (_, Some(button)) = cheapest[&state] {
+        presses[button] += 1;
+        for &i in &machine.buttons[button].toggle_indices {
+            state[i] -= 1;
+        }
+    }
+    PressPlan { total: machine.cost(&presses), presses }
+}
+
+/// The work one `search_min_presses` run did.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SearchStats {
+    /// States taken off the queue.
+    pub expanded: u64,
+    /// States put on the queue, counting a state again when a cheaper way to
+    /// it turns up.
+    pub queued: u64,
+    /// Presses skipped because they would overshoot a joltage.
+    pub overshoots: u64,
+    /// Presses skipped because they reach a state already queued as cheaply.
+    pub revisits: u64,
+    /// Presses skipped because not even fractional presses get from them to
+    /// the joltages.
+    pub dead_ends: u64,
+}
+
+impl SearchStats {
+    pub fn add(&mut self, other: SearchStats) {
+        self.expanded += other.expanded;
+        self.queued += other.queued;
+        self.overshoots += other.overshoots;
+        self.revisits += other.revisits;
+        self.dead_ends += other.dead_ends;
+    }
+}
+
+/// Lower bounds on the cost still needed to raise the counters by
+/// `remaining`, from the dual of the LP relaxation `min c·x` subject to
+/// `A·x = remaining, x ≥ 0`. Any `y` with `Aᵀ·y ≤ c` (no button's entries sum
+/// past its cost) bounds it by `remaining·y`, and a press lowers that bound by
+/// at most its cost, so the A* search can trust it without ever reopening a
+/// state. Dividing each button's column by its cost makes that `Aᵀ·y ≤ 1`.
+///
+/// The LP optimum is always at a vertex of `Aᵀ·y ≤ 1`, so with every vertex
+/// at hand the best of them is the LP bound itself. Vertices are where some
+/// square set of buttons is tight, which is few enough to list for machines of
+/// the usual size. Larger machines fall back to a single least-squares dual.
+///
+/// When not even fractional presses reach `remaining`, the dual is unbounded
+/// instead: by Farkas' lemma some ray `d` with `Aᵀ·d ≤ 0` has `remaining·d > 0`,
+/// and one of the extreme rays does. Those are where all but one of the
+/// counters' worth of buttons is tight, and they are found exactly, so a
+/// state they rule out is certainly a dead end. On a machine with only one
+/// way to reach its joltages, that leaves just the states on the way there.
+struct LowerBound {
+    /// Counters whose rows of `A` are linearly independent. Any other
+    /// counter's joltage follows from theirs, and leaving them out keeps the
+    /// vertices finite.
+    counters: Vec<usize>,
+    /// Dual vertices, over `counters`.
+    duals: Vec<Vec<f64>>,
+    /// Extreme rays of the dual, over `counters`.
+    rays: Vec<Vec<i64>>,
+    /// Combinations of all the counters that no button changes, which the
+    /// remaining joltages must therefore keep at 0.
+    dependencies: Vec<Vec<i64>>,
+    cheapest_press: i64,
+}
+
+/// Slack for floating point error, so a bound of 6.9999999 still rounds up to 7
+/// but never past the true value.
+const EPSILON: f64 = 1e-6;
+/// The most square button sets to try as dual vertices before falling back.
+const MAX_BASES: u64 = 20_000;
+
+impl LowerBound {
+    fn new(machine: &Machine) -> Self {
+        let num_counters = machine.joltage_target.len();
+        let touches = |button: &Button, counter: usize| button.toggle_indices.contains(&counter) as i64;
+        let transposed = Matrix::from_fn(machine.buttons.len(), num_counters, |button, counter| {
+            Rational::from(touches(&machine.buttons[button], counter))
+        });
+        let rref = transposed.rref();
+        let counters = rref.as_ref().map_or_else(|_| (0..num_counters).collect(), |rref| rref.pivot_cols.clone());
+        // Without the dependencies or rays the bound is only weaker, never wrong.
+        let dependencies = rref.and_then(|rref| rref.integer_nullspace()).unwrap_or_default();
+        let wiring: Vec<Vec<i64>> =
+            machine.buttons.iter().map(|button| counters.iter().map(|&counter| touches(button, counter)).collect()).collect();
+        let rays = match counters.len().checked_sub(1) {
+            Some(tight) if binomial(wiring.len(), tight) <= MAX_BASES => dual_rays(&wiring, counters.len()).unwrap_or_default(),
+            _ => vec![],
+        };
+        // Each button's column of `A` over its cost, over just `counters`.
+        let columns: Vec<Vec<f64>> = machine
+            .buttons
+            .iter()
+            .map(|button| counters.iter().map(|&counter| touches(button, counter) as f64 / button.cost as f64).collect())
+            .collect();
+        let duals = if binomial(columns.len(), counters.len()) <= MAX_BASES {
+            dual_vertices(&columns, counters.len())
+        } else {
+            least_squares_dual(&columns, counters.len()).into_iter().collect()
+        };
+        let cheapest_press = machine.buttons.iter().map(|button| button.cost).min().unwrap_or(1);
+        LowerBound { counters, duals, rays, dependencies, cheapest_press }
+    }
+
+    /// Also never less than the largest remaining joltage times the cheapest
+    /// press, since a press adds at most one to each counter. `None` if no
+    /// presses get from `state` to `target`.
+    fn estimate(&self, target: &[i64], state: &[i64]) -> Option<i64> {
+        let remaining = |counter: usize| i128::from(target[counter] - state[counter]);
+        let weighed = |multipliers: &[i64], counters: &[usize]| -> i128 {
+            multipliers.iter().zip(counters).map(|(&multiplier, &counter)| i128::from(multiplier) * remaining(counter)).sum()
+        };
+        let all_counters: Vec<usize> = (0..target.len()).collect();
+        if self.dependencies.iter().any(|dependency| weighed(dependency, &all_counters) != 0)
+            || self.rays.iter().any(|ray| weighed(ray, &self.counters) > 0)
+        {
+            return None;
+        }
+        let largest_remaining = target.iter().zip(state).map(|(t, s)| t - s).max().unwrap_or(0);
+        let remaining: Vec<f64> = self.counters.iter().map(|&counter| remaining(counter) as f64).collect();
+        let estimate = self
+            .duals
+            .iter()
+            .map(|dual| {
+                let bound: f64 = dual.iter().zip(&remaining).map(|(y, r)| y * r).sum();
+                (bound - EPSILON).ceil() as i64
+            })
+            .fold(largest_remaining * self.cheapest_press, i64::max);
+        Some(estimate)
+    }
+}
+
+/// Every `y` with `columnᵀ·y = 1` for `size` of the columns and `≤ 1` for the
+/// rest, solving each square system with `rsparse`'s sparse LU.
+fn dual_vertices(columns: &[Vec<f64>], size: usize) -> Vec<Vec<f64>> {
+    let mut vertices = vec![];
+    let mut seen = HashSet::new();
+    for basis in combinations(columns.len(), size) {
+        let rows: Vec<Vec<f64>> = basis.iter().map(|&button| columns[button].clone()).collect();
+        // lusol reads the right-hand side from and writes the solution to one buffer.
+        let mut dual = vec![1.0; size];
+        if rsparse::lusol(&rsparse::data::Sprs::new_from_vec(&rows), &mut dual, ordering(size, LU_ORDERING), 1.0).is_err()
+            || !dual.iter().all(|y| y.is_finite())
+            || largest_column_sum(columns, &dual) > 1.0 + EPSILON
+        {
+            continue;
+        }
+        let dual = scale_to_feasible(columns, dual);
+        let key: Vec<i64> = dual.iter().map(|y| (y / EPSILON).round() as i64).collect();
+        if seen.insert(key) {
+            vertices.push(dual);
+        }
+    }
+    vertices
+}
+
+/// Every `d` with `wiringᵀ·d = 0` for `size - 1` of the buttons and `≤ 0` for
+/// the rest, found with exact elimination and scaled to whole numbers.
+fn dual_rays(wiring: &[Vec<i64>], size: usize) -> Result<Vec<Vec<i64>>, Overflow> {
+    let mut rays = vec![];
+    for tight in combinations(wiring.len(), size - 1) {
+        let matrix = Matrix::from_fn(tight.len(), size, |row, counter| Rational::from(wiring[tight[row]][counter]));
+        // Tight buttons that are not independent pin down no single direction.
+        let nullspace = matrix.rref()?.integer_nullspace()?;
+        let [direction] = nullspace.as_slice() else {
+            continue;
+        };
+        let opposite: Vec<i64> = direction.iter().map(|d| -d).collect();
+        for ray in [direction.clone(), opposite] {
+            let never_raised = wiring.iter().all(|column| column.iter().zip(&ray).map(|(a, d)| a * d).sum::<i64>() <= 0);
+            if never_raised && !rays.contains(&ray) {
+                rays.push(ray);
+            }
+        }
+    }
+    Ok(rays)
+}
+
+/// Solves `Aᵀ·y = 1` in the least squares sense with `rsparse`'s sparse QR.
+/// Such a `y` is close to tight on every button at once, so scaled down until
+/// none is over, it still tends to give a strong bound.
+fn least_squares_dual(columns: &[Vec<f64>], size: usize) -> Option<Vec<f64>> {
+    let mut dual = vec![0.0; columns.len().max(size)];
+    dual[..columns.len()].fill(1.0);
+    rsparse::qrsol(&rsparse::data::Sprs::new_from_vec(columns), &mut dual, ordering(size, QR_ORDERING));
+    dual.truncate(size);
+    dual.iter().all(|y| y.is_finite()).then(|| scale_to_feasible(columns, dual))
+}
+
+/// `rsparse`'s orderings for LU and QR, which reorder the columns to keep the
+/// factors sparse.
+const LU_ORDERING: i8 = 1;
+const QR_ORDERING: i8 = 2;
+
+/// `preferred` for a matrix with `size` columns, or the natural ordering for a
+/// single column, which has nothing to reorder and underflows `rsparse`'s
+/// fill-reducing one.
+fn ordering(size: usize, preferred: i8) -> i8 {
+    if size < 2 { -1 } else { preferred }
+}
+
+fn largest_column_sum(columns: &[Vec<f64>], dual: &[f64]) -> f64 {
+    columns.iter().map(|column| column.iter().zip(dual).map(|(a, y)| a * y).sum::<f64>()).fold(0.0, f64::max)
+}
+
+/// Scales `dual` down just enough that no column sums past 1 against it.
+fn scale_to_feasible(columns: &[Vec<f64>], dual: Vec<f64>) -> Vec<f64> {
+    let largest_sum = largest_column_sum(columns, &dual);
+    if largest_sum > 1.0 { dual.iter().map(|y| y / largest_sum).collect() } else { dual }
+}
+
+/// Every `size`-element subset of `0..n`, as sorted indices.
+fn combinations(n: usize, size: usize) -> impl Iterator<Item = Vec<usize>> {
+    let mut next = (size <= n).then(|| (0..size).collect::<Vec<usize>>());
+    std::iter::from_fn(move || {
+        let current = next.take()?;
+        let mut following = current.clone();
+        if let Some(index) = (0..size).rev().find(|&i| following[i] < n - size + i) {
+            following[index] += 1;
+            for i in index + 1..size {
+                following[i] = following[i - 1] + 1;
+            }
+            next = Some(following);
+        }
+        Some(current)
+    })
+}
+
+fn binomial(n: usize, k: usize) -> u64 {
+    (0..k.min(n - k.min(n))).fold(1, |result: u64, i| result.saturating_mul((n - i) as u64) / (i as u64 + 1))
+}
+
+pub(crate) fn press_button_on_joltages(joltages: &[i64], button: &Button) -> Vec<i64> {
+    let mut new_joltages = joltages.to_vec();
+    for &i in &button.toggle_indices {
+        new_joltages[i] += 1;
+    }
+    new_joltages
+}
+
+fn could_be_valid_joltage_option(new_joltages: &[i64], target: &[i64]) -> bool {
+    for i in 0..new_joltages.len() {
+        if new_joltages[i] > target[i] {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Budget,
+        tests::{EXAMPLE, machines, random_machines},
+    };
+
+    /// The cheapest cost the ILP and the search each find, after checking
+    /// their presses, or `None` if they find the joltages out of reach.
+    fn costs(machine: &Machine) -> [Option<i64>; 2] {
+        let ilp = min_presses(machine, &mut Meter::new(Budget::default())).unwrap();
+        let (search, _) = search_min_presses(machine, &mut Meter::new(Budget::default()));
+        [("ilp", ilp), ("search", search)].map(|(name, outcome)| match outcome {
+            Outcome::Solved(plan) => {
+                machine.check_joltages(&plan).unwrap_or_else(|err| panic!("{name} on {machine:?}: {err}"));
+                Some(plan.total)
+            }
+            Outcome::Infeasible(_) => None,
+            outcome => panic!("{name} on {machine:?}: {outcome:?}"),
+        })
+    }
+
+    #[test]
+    fn ilp_and_search_agree_on_the_example() {
+        let costs: Vec<[Option<i64>; 2]> = machines(EXAMPLE).iter().map(costs).collect();
+        assert_eq!(costs, [[Some(10); 2], [Some(12); 2], [Some(11); 2]]);
+    }
+
+    #[test]
+    fn ilp_and_search_agree_on_random_machines() {
+        for machine in random_machines(300, 2) {
+            let costs = costs(&machine);
+            assert!(costs[0].is_some() && costs[0] == costs[1], "{machine:?}: {costs:?}");
+        }
+    }
+
+    #[test]
+    fn ilp_and_search_agree_there_is_no_answer() {
+        // (0,1) twice for counter 1 already puts counter 0 past 1.
+        let machine = &machines("[..] (0) (0,1) {1,2}")[0];
+        assert_eq!(costs(machine), [None, None]);
+        let outcome = min_presses(machine, &mut Meter::new(Budget::default())).unwrap();
+        assert!(matches!(outcome, Outcome::Infeasible(Infeasibility::NoNonNegativeSolution)), "{outcome:?}");
+        let start = vec![0; 2];
+        assert_eq!(LowerBound::new(machine).estimate(&machine.joltage_target, &start), None);
+    }
+
+    #[test]
+    fn the_search_walks_straight_to_the_only_answer() {
+        // Only (0) x15, (0,1) x5 and (1,2) x25 work, and every state off that
+        // way is a dead end.
+        let machine = &machines("[...] (0) (0,1) (1,2) {20,30,25}")[0];
+        let (outcome, stats) = search_min_presses(machine, &mut Meter::new(Budget::default()));
+        assert!(matches!(outcome, Outcome::Solved(PressPlan { total: 45, .. })), "{outcome:?}");
+        assert_eq!(stats.expanded, 46);
+        assert!(stats.dead_ends > 0);
+    }
+
+    #[test]
+    fn inconsistent_joltages_come_with_a_certificate() {
+        let machine = &machines("[....] (0,1) (2,3) (0,1,2,3) {1,2,3,3}")[0];
+        let Ok(Outcome::Infeasible(Infeasibility::Inconsistent { multipliers, total })) = min_presses(machine, &mut Meter::new(Budget::default()))
+        else {
+            panic!("the ILP found no inconsistency in {machine:?}");
+        };
+        for button in &machine.buttons {
+            let change: i64 = button.toggle_indices.iter().map(|&counter| multipliers[counter]).sum();
+            assert_eq!(change, 0, "{button} changes {multipliers:?}");
+        }
+        let target: i64 = multipliers.iter().zip(&machine.joltage_target).map(|(m, joltage)| m * joltage).sum();
+        assert_eq!(target, total);
+        assert_ne!(total, 0);
+    }
+
+    #[test]
+    fn the_lower_bound_never_overestimates() {
+        for machine in machines(EXAMPLE).iter().chain(&random_machines(100, 3)) {
+            let Ok(Outcome::Solved(plan)) = min_presses(machine, &mut Meter::new(Budget::default())) else {
+                panic!("the ILP could not solve {machine:?}");
+            };
+            let start = vec![0; machine.joltage_target.len()];
+            let estimate = LowerBound::new(machine).estimate(&machine.joltage_target, &start);
+            assert!(estimate.is_some_and(|estimate| estimate <= plan.total), "{machine:?}: {estimate:?}");
+        }
+    }
+}
@@ -0,0 +1,496 @@
+pub mod joltage;
+pub mod lights;
+mod outcome;
+mod report;
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    sync::Mutex,
+    time::Duration,
+};
+
+use aoc_common::{Answer, BitSet, Overflow, ParseError, Solution, parse_lines, parse_number};
+use clap::ValueEnum;
+use joltage::SearchStats;
+pub use outcome::{Budget, Infeasibility, Meter, Outcome, PressPlan};
+use rayon::prelude::*;
+pub use report::{MachineRecord, Part, PartRecord, Report, ReportFormat};
+
+#[derive(Default)]
+pub struct Day10 {
+    pub lights_solver: LightsSolver,
+    pub joltage_solver: JoltageSolver,
+    /// Print how much work the joltage search did to stderr.
+    pub search_stats: bool,
+    /// Print the presses found for each machine, after replaying them to
+    /// check they work.
+    pub witnesses: bool,
+    /// How much work to spend on each machine, for each part.
+    pub budget: Budget,
+    /// Where to record how each machine went, if anywhere.
+    pub report: Option<Report>,
+}
+
+/// How part 1 finds the cheapest presses.
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub enum LightsSolver {
+    /// Gaussian elimination over GF(2)
+    #[default]
+    Elimination,
+    /// The original breadth-first search over light states, to cross-check elimination
+    Bfs,
+    /// Every subset of each half of the buttons, joined on the target
+    MeetInTheMiddle,
+}
+
+/// How part 2 finds the cheapest presses.
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub enum JoltageSolver {
+    /// Exact integer linear programming over the machine's equations
+    #[default]
+    Ilp,
+    /// A* search press by press, guided by a lower bound from the LP relaxation
+    Search,
+}
+
+impl Solution for Day10 {
+    type Input = Vec<Machine>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_lines(input, parse_to_machine)?)
+    }
+
+    fn part_1(&self, machines: &Self::Input) -> impl Answer {
+        self.total(Part::Lights, machines, Machine::check_lights, |machine, meter| match self.lights_solver {
+            LightsSolver::Elimination => lights::min_presses(machine, meter),
+            LightsSolver::Bfs => lights::bfs_min_presses(machine, meter),
+            LightsSolver::MeetInTheMiddle => lights::meet_in_the_middle_min_presses(machine, meter),
+        })
+    }
+
+    fn part_2(&self, machines: &Self::Input) -> impl Answer {
+        let total_stats = Mutex::new(SearchStats::default());
+        let total = self.total(Part::Joltages, machines, Machine::check_joltages, |machine, meter| match self.joltage_solver {
+            JoltageSolver::Ilp => joltage::min_presses(machine, meter).unwrap_or_else(|err| Outcome::Failed(err.to_string())),
+            JoltageSolver::Search => {
+                let (outcome, stats) = joltage::search_min_presses(machine, meter);
+                total_stats.lock().expect("another search panicked").add(stats);
+                outcome
+            }
+        });
+        if self.search_stats && matches!(self.joltage_solver, JoltageSolver::Search) {
+            let total_stats = total_stats.into_inner().expect("another search panicked");
+            eprintln!(
+                "search: {} states expanded, {} queued, {} presses overshot, {} revisited, {} dead ends",
+                total_stats.expanded, total_stats.queued, total_stats.overshoots, total_stats.revisits, total_stats.dead_ends
+            );
+        }
+        total
+    }
+}
+
+impl Day10 {
+    /// Solves the machines in parallel, each within its own budget, then
+    /// reports the ones that could not be solved to stderr, in order, rather
+    /// than stopping at them. With `witnesses`, presses that fail `check`
+    /// count as a failed solve.
+    fn total(
+        &self,
+        part: Part,
+        machines: &[Machine],
+        check: fn(&Machine, &PressPlan) -> Result<(), String>,
+        solve: impl Fn(&Machine, &mut Meter) -> Outcome + Sync,
+    ) -> Total {
+        let outcomes: Vec<(Outcome, Duration, u64)> = machines
+            .par_iter()
+            .map(|machine| {
+                let mut meter = Meter::new(self.budget);
+                let outcome = match solve(machine, &mut meter) {
+                    Outcome::Solved(plan) if self.witnesses => match check(machine, &plan) {
+                        Ok(()) => Outcome::Solved(plan),
+                        Err(err) => Outcome::Failed(format!("the presses found do not work: {err}")),
+                    },
+                    outcome => outcome,
+                };
+                (outcome, meter.elapsed(), meter.steps())
+            })
+            .collect();
+        let mut total = Total::default();
+        for (index, (machine, (outcome, time, steps))) in machines.iter().zip(outcomes).enumerate() {
+            let line = index + 1;
+            if let Some(report) = &self.report {
+                report.record(index, machine, part, PartRecord::new(&outcome, time, steps));
+            }
+            match outcome {
+                Outcome::Solved(plan) => {
+                    if self.witnesses {
+                        println!("machine {line} {part}: {}", machine.describe_presses(&plan.presses));
+                    }
+                    total.sum = total.sum.and_then(|sum| sum.checked_add(plan.total));
+                }
+                Outcome::Infeasible(reason) => {
+                    eprintln!("machine {line} {part}: unreachable, {reason}");
+                    total.unsolved.push(line);
+                }
+                Outcome::BudgetExhausted { steps, elapsed } => {
+                    eprintln!("machine {line} {part}: gave up after {steps} steps in {elapsed:.2?}");
+                    total.unsolved.push(line);
+                }
+                Outcome::Failed(reason) => {
+                    eprintln!("machine {line} {part}: failed, {reason}");
+                    total.unsolved.push(line);
+                }
+            }
+        }
+        total
+    }
+}
+
+/// A part's answer: the cheapest presses' cost summed over the machines solved,
+/// along with the lines of any that were not.
+#[derive(Debug)]
+struct Total {
+    /// `None` once the sum has overflowed an `i64`.
+    sum: Option<i64>,
+    unsolved: Vec<usize>,
+}
+
+impl Default for Total {
+    fn default() -> Self {
+        Total { sum: Some(0), unsolved: vec![] }
+    }
+}
+
+/// The sum only if every machine was solved, since a sum missing some machines
+/// is not the answer and must not be submitted as one.
+impl Answer for Total {
+    fn render(&self) -> Result<String, String> {
+        let Some(sum) = self.sum else {
+            return Err("the machines' costs add up past an i64".to_string());
+        };
+        if self.unsolved.is_empty() {
+            return Ok(sum.to_string());
+        }
+        let lines: Vec<String> = self.unsolved.iter().map(usize::to_string).collect();
+        let noun = if lines.len() == 1 { "machine on line" } else { "machines on lines" };
+        Err(format!("could not solve the {noun} {} (the rest sum to {sum})", lines.join(", ")))
+    }
+}
+
+#[derive(Debug)]
+pub struct Machine {
+    light_target: BitSet,
+    /// Sorted by how many lights they wire, fewest first.
+    buttons: Vec<Button>,
+    joltage_target: Vec<i64>,
+}
+
+impl Machine {
+    /// The lights after pressing each button as many times as `presses` says,
+    /// starting from all off.
+    pub fn replay_lights(&self, presses: &[i64]) -> BitSet {
+        let mut lights = BitSet::new(self.light_target.len());
+        for (button, &count) in self.buttons.iter().zip(presses) {
+            for _ in 0..count {
+                lights = lights::press_button_on_lights(&lights, button);
+            }
+        }
+        lights
+    }
+
+    /// The joltages after pressing each button as many times as `presses`
+    /// says, starting from all zero.
+    pub fn replay_joltages(&self, presses: &[i64]) -> Vec<i64> {
+        let mut joltages = vec![0; self.joltage_target.len()];
+        for (button, &count) in self.buttons.iter().zip(presses) {
+            for _ in 0..count {
+                joltages = joltage::press_button_on_joltages(&joltages, button);
+            }
+        }
+        joltages
+    }
+
+    /// Replays `plan` to check it lights up exactly the diagram's lights.
+    pub fn check_lights(&self, plan: &PressPlan) -> Result<(), String> {
+        self.check_plan(plan)?;
+        let lights = self.replay_lights(&plan.presses);
+        if lights != self.light_target {
+            return Err(format!("they light up {} instead of {}", diagram(&lights), diagram(&self.light_target)));
+        }
+        Ok(())
+    }
+
+    /// Replays `plan` to check it raises every counter to exactly its joltage.
+    pub fn check_joltages(&self, plan: &PressPlan) -> Result<(), String> {
+        self.check_plan(plan)?;
+        let joltages = self.replay_joltages(&plan.presses);
+        if joltages != self.joltage_target {
+            return Err(format!("they reach the joltages {joltages:?} instead of {:?}", self.joltage_target));
+        }
+        Ok(())
+    }
+
+    fn check_plan(&self, plan: &PressPlan) -> Result<(), String> {
+        if plan.presses.len() != self.buttons.len() {
+            return Err(format!("they press {} buttons of {}", plan.presses.len(), self.buttons.len()));
+        }
+        if let Some(count) = plan.presses.iter().find(|&&count| count < 0) {
+            return Err(format!("they press a button {count} times"));
+        }
+        let cost = self.cost(&plan.presses);
+        if cost != plan.total {
+            return Err(format!("they cost {cost} in total, not {}", plan.total));
+        }
+        Ok(())
+    }
+
+    /// Checks that pressing every button `most_presses` times costs little
+    /// enough that a solver can add two such costs without overflowing, so
+    /// the costs it sums up afterwards need no checks.
+    fn check_costs(&self, most_presses: i64) -> Result<(), Overflow> {
+        self.buttons
+            .iter()
+            .try_fold(0_i64, |sum, button| button.cost.checked_mul(most_presses).and_then(|most| sum.checked_add(most)))
+            .and_then(|most| most.checked_mul(2))
+            .map(|_| ())
+            .ok_or(Overflow)
+    }
+
+    /// What pressing each button as many times as `presses` says costs.
+    pub fn cost(&self, presses: &[i64]) -> i64 {
+        self.buttons.iter().zip(presses).map(|(button, &count)| button.cost * count).sum()
+    }
+
+    /// The buttons pressed, in the order the input lists them, like
+    /// `2 presses: #1 (3) x1, #4 (0,2) x1`. Buttons are numbered from 0, and
+    /// the total cost follows the presses if it differs from them.
+    pub fn describe_presses(&self, presses: &[i64]) -> String {
+        let mut pressed: Vec<(&Button, i64)> = self.buttons.iter().zip(presses.iter().copied()).filter(|&(_, count)| count > 0).collect();
+        pressed.sort_by_key(|(button, _)| button.index);
+        let described: Vec<String> = pressed.iter().map(|(button, count)| format!("#{} {button} x{count}", button.index)).collect();
+        let (count, cost) = (presses.iter().sum::<i64>(), self.cost(presses));
+        let cost = if cost == count { String::new() } else { format!(" costing {cost}") };
+        format!("{count} presses{cost}: {}", described.join(", "))
+    }
+}
+
+#[derive(Debug)]
+struct Button {
+    /// Where the button is among the machine's buttons in the input.
+    index: usize,
+    toggle_indices: Vec<usize>,
+    /// What each press costs: 1 unless the input gives it after a colon,
+    /// like `(0,2):5`.
+    cost: i64,
+}
+
+/// Writes the button the way the input does, like `(0,2)` or `(0,2):5`.
+impl Display for Button {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let indices: Vec<String> = self.toggle_indices.iter().map(usize::to_string).collect();
+        write!(f, "({})", indices.join(","))?;
+        if self.cost != 1 {
+            write!(f, ":{}", self.cost)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_to_machine(line: &str) -> Result<Machine, ParseError> {
+    let mut lights: Option<(&str, BitSet)> = None;
+    let mut joltage_target: Option<Vec<i64>> = None;
+    let mut buttons: Vec<(&str, Button)> = vec![];
+    for component in line.split_whitespace() {
+        if component.starts_with("[") {
+            lights = Some((component, parse_lights(line, component)?));
+        } else if component.starts_with("{") {
+            joltage_target = Some(parse_joltages(line, component)?);
+        } else if component.starts_with("(") {
+            buttons.push((component, parse_button(line, component, buttons.len())?));
+        } else {
+            return Err(ParseError::at(line, component, format!("unexpected '{component}', expected [lights], (button) or {{joltages}}")));
+        }
+    }
+    let end_of_line = &line[line.len()..];
+    let Some((lights, light_target)) = lights else {
+        return Err(ParseError::at(line, end_of_line, "missing the [lights] diagram"));
+    };
+    let Some(joltage_target) = joltage_target else {
+        return Err(ParseError::at(line, end_of_line, "missing the {joltages} requirements"));
+    };
+    let num_lights = lights.len() - 2;
+    if joltage_target.len() != num_lights {
+        let message = format!("found {} joltages for {num_lights} lights", joltage_target.len());
+        return Err(ParseError::at(line, lights, message));
+    }
+    if let Some((component, _)) = buttons.iter().find(|(_, button)| button.toggle_indices.iter().any(|&i| i >= num_lights)) {
+        return Err(ParseError::at(line, component, format!("button wires a light past the last of the {num_lights} lights")));
+    }
+    let mut buttons: Vec<Button> = buttons.into_iter().map(|(_, button)| button).collect();
+    buttons.sort_by_key(|button| button.toggle_indices.len());
+    Ok(Machine {
+        light_target,
+        joltage_target,
+        buttons,
+    })
+}
+
+/// The text between a component's opening and closing brackets.
+fn bracketed<'a>(line: &str, component: &'a str, close: char) -> Result<&'a str, ParseError> {
+    if component.len() < 2 || !component.ends_with(close) {
+        return Err(ParseError::at(line, component, format!("expected '{component}' to end with '{close}'")));
+    }
+    Ok(&component[1..component.len() - 1])
+}
+
+fn parse_lights(line: &str, lights: &str) -> Result<BitSet, ParseError> {
+    let diagram = bracketed(line, lights, ']')?;
+    if let Some((index, c)) = diagram.char_indices().find(|(_, c)| !matches!(c, '.' | '#')) {
+        let bad = &diagram[index..index + c.len_utf8()];
+        return Err(ParseError::at(line, bad, format!("invalid light '{c}', expected '.' or '#'")));
+    }
+    Ok(diagram.chars().map(|c| c == '#').collect())
+}
+
+/// Writes lights the way the input's diagrams do, like `[.##.]`.
+fn diagram(lights: &BitSet) -> String {
+    let lights: String = (0..lights.len()).map(|light| if lights.get(light) { '#' } else { '.' }).collect();
+    format!("[{lights}]")
+}
+
+fn parse_button(line: &str, button: &str, index: usize) -> Result<Button, ParseError> {
+    let (wiring, cost) = match button.split_once("):") {
+        Some((indices, cost)) => (&button[..indices.len() + 1], parse_number(line, cost)?),
+        None => (button, 1),
+    };
+    if cost < 1 {
+        return Err(ParseError::at(line, button, format!("button costs {cost}, but every press must cost at least 1")));
+    }
+    Ok(Button {
+        index,
+        toggle_indices: bracketed(line, wiring, ')')?
+            .split(",")
+            .map(|num| parse_number(line, num))
+            .collect::<Result<_, _>>()?,
+        cost,
+    })
+}
+
+fn parse_joltages(line: &str, joltages: &str) -> Result<Vec<i64>, ParseError> {
+    bracketed(line, joltages, '}')?
+        .split(",")
+        .map(|c| {
+            let joltage = parse_number(line, c)?;
+            if joltage < 0 {
+                return Err(ParseError::at(line, c, format!("joltage {joltage} is negative, but presses only raise counters")));
+            }
+            Ok(joltage)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub(crate) const EXAMPLE: &str = include_str!("examples/example_1.txt");
+    /// The example's first machine with two of its buttons made dearer.
+    const WEIGHTED: &str = "[.##.] (3) (1,3):2 (2) (2,3) (0,2):5 (0,1) {3,5,4,7}";
+
+    pub(crate) fn machines(input: &str) -> Vec<Machine> {
+        Day10::default().parse(input).unwrap()
+    }
+
+    /// Small machines with a few lights and buttons, some of them costing
+    /// more than 1, as the input would write them. The joltages are what some
+    /// presses reach, so there is always an answer, but the lights are picked
+    /// at random and may be out of reach.
+    pub(crate) fn random_machines(count: usize, seed: u64) -> Vec<Machine> {
+        let mut state = seed;
+        // xorshift64, which is plenty to vary the machines.
+        let mut next = move |below: usize| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % below as u64) as usize
+        };
+        let lines: Vec<String> = (0..count)
+            .map(|_| {
+                let num_lights = 2 + next(4);
+                let lights: String = (0..num_lights).map(|_| if next(2) == 1 { '#' } else { '.' }).collect();
+                let mut joltages = vec![0; num_lights];
+                let buttons: Vec<String> = (0..2 + next(6))
+                    .map(|_| {
+                        let mut wired: Vec<usize> = (0..num_lights).filter(|_| next(2) == 1).collect();
+                        if wired.is_empty() {
+                            wired.push(next(num_lights));
+                        }
+                        let presses = next(4);
+                        wired.iter().for_each(|&light| joltages[light] += presses);
+                        let wired: Vec<String> = wired.iter().map(usize::to_string).collect();
+                        let cost = [1, 1, 2, 3][next(4)];
+                        let cost = if cost == 1 { String::new() } else { format!(":{cost}") };
+                        format!("({}){cost}", wired.join(","))
+                    })
+                    .collect();
+                let joltages: Vec<String> = joltages.iter().map(usize::to_string).collect();
+                format!("[{lights}] {} {{{}}}", buttons.join(" "), joltages.join(","))
+            })
+            .collect();
+        machines(&lines.join("\n"))
+    }
+
+    #[test]
+    fn every_solver_finds_the_examples_answers() {
+        for lights_solver in [LightsSolver::Elimination, LightsSolver::Bfs, LightsSolver::MeetInTheMiddle] {
+            for joltage_solver in [JoltageSolver::Ilp, JoltageSolver::Search] {
+                let day = Day10 { lights_solver, joltage_solver, witnesses: true, ..Day10::default() };
+                let machines = day.parse(EXAMPLE).unwrap();
+                assert_eq!(day.part_1(&machines).render(), Ok("7".to_string()), "{lights_solver:?}");
+                assert_eq!(day.part_2(&machines).render(), Ok("33".to_string()), "{joltage_solver:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn every_solver_finds_the_cheapest_weighted_presses() {
+        // Lights: (1,3):2 and (2,3) for 3, rather than three presses costing 1.
+        // Joltages: (3) once, (1,3):2 twice, (2,3) four times and (0,1) three
+        // times for 12, never touching (0,2):5.
+        for lights_solver in [LightsSolver::Elimination, LightsSolver::Bfs, LightsSolver::MeetInTheMiddle] {
+            for joltage_solver in [JoltageSolver::Ilp, JoltageSolver::Search] {
+                let day = Day10 { lights_solver, joltage_solver, witnesses: true, ..Day10::default() };
+                let machines = day.parse(WEIGHTED).unwrap();
+                assert_eq!(day.part_1(&machines).render(), Ok("3".to_string()), "{lights_solver:?}");
+                assert_eq!(day.part_2(&machines).render(), Ok("12".to_string()), "{joltage_solver:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn a_part_with_unsolved_machines_has_no_answer() {
+        let day = Day10::default();
+        let machines = day.parse("[#.] (0,1) {1,1}\n[##] (0,1) {1,2}").unwrap();
+        assert_eq!(
+            day.part_1(&machines).render(),
+            Err("could not solve the machine on line 1 (the rest sum to 1)".to_string())
+        );
+        assert_eq!(
+            day.part_2(&machines).render(),
+            Err("could not solve the machine on line 2 (the rest sum to 1)".to_string())
+        );
+    }
+
+    #[test]
+    fn replaying_catches_wrong_presses() {
+        let machine = &machines(WEIGHTED)[0];
+        // The buttons are sorted by how many lights they wire: (3), (2), then the pairs.
+        let presses = |presses: &[i64]| PressPlan { total: machine.cost(presses), presses: presses.to_vec() };
+        assert_eq!(machine.check_lights(&presses(&[0, 0, 1, 1, 0, 0])), Ok(()));
+        assert!(machine.check_lights(&presses(&[1, 0, 1, 1, 0, 0])).unwrap_err().starts_with("they light up [.###]"));
+        assert!(machine.check_joltages(&presses(&[1, 0, 1, 1, 0, 0])).is_err());
+        let undercharged = PressPlan { total: 2, presses: vec![0, 0, 1, 1, 0, 0] };
+        assert_eq!(machine.check_lights(&undercharged), Err("they cost 3 in total, not 2".to_string()));
+    }
+}
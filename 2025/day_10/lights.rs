@@ -0,0 +1,259 @@
+//! Part 1: the cheapest presses that toggle exactly the lights in the diagram,
+//! which are the fewest when every button costs 1.
+//! Pressing a button twice undoes it, so this is a linear system over GF(2)
+//! in which each button is pressed once or not at all.
+
+use std::{cmp::Reverse, collections::HashMap};
+
+use aoc_common::BitSet;
+use priority_queue::PriorityQueue;
+
+use crate::{Button, Infeasibility, Machine, Meter, Outcome, PressPlan};
+
+/// Solves the machine's lights by Gaussian elimination over GF(2). Every set of
+/// presses that reaches the target is one solution XORed with some combination
+/// of the nullspace basis, so the cheapest is found by walking all of those
+/// combinations in Gray code order, one XOR per step. That
+/// is `2^k` steps for `k` free buttons, and each is a step of the budget.
+pub fn min_presses(machine: &Machine, meter: &mut Meter) -> Outcome {
+    if let Err(overflow) = machine.check_costs(1) {
+        return Outcome::Failed(overflow.to_string());
+    }
+    let num_buttons = machine.buttons.len();
+    let num_lights = machine.light_target.len();
+    // One row per light: which buttons toggle it, whether it must end up on,
+    // and which lights' rows were added up to make it.
+    let mut rows: Vec<BitSet> = (0..num_lights)
+        .map(|light| {
+            let toggled_by = machine.buttons.iter().map(|button| button.toggle_indices.contains(&light));
+            let made_from = (0..num_lights).map(|other| other == light);
+            toggled_by.chain([machine.light_target.get(light)]).chain(made_from).collect()
+        })
+        .collect();
+    let mut pivot_cols = vec![];
+    let mut free = vec![];
+    for col in 0..num_buttons {
+        let rank = pivot_cols.len();
+        let Some(pivot_row) = (rank..rows.len()).find(|&row| rows[row].get(col)) else {
+            free.push(col);
+            continue;
+        };
+        rows.swap(rank, pivot_row);
+        let pivot = rows[rank].clone();
+        for (row, values) in rows.iter_mut().enumerate() {
+            if row != rank && values.get(col) {
+                *values ^= &pivot;
+            }
+        }
+        pivot_cols.push(col);
+    }
+    if let Some(row) = rows[pivot_cols.len()..].iter().find(|row| row.get(num_buttons)) {
+        let lights = row.ones().filter(|&col| col > num_buttons).map(|col| col - num_buttons - 1).collect();
+        return Outcome::Infeasible(Infeasibility::OddLights(lights));
+    }
+
+    let mut presses = BitSet::new(num_buttons);
+    for (row, &col) in rows.iter().zip(&pivot_cols) {
+        presses.set(col, row.get(num_buttons));
+    }
+    let nullspace: Vec<BitSet> = free
+        .iter()
+        .map(|&free_col| {
+            let mut vector = BitSet::new(num_buttons);
+            vector.set(free_col, true);
+            for (row, &col) in rows.iter().zip(&pivot_cols) {
+                vector.set(col, row.get(free_col));
+            }
+            vector
+        })
+        .collect();
+    if free.len() >= u64::BITS as usize {
+        // No budget stretches to 2^64 combinations, so there is no point starting.
+        return meter.exhausted();
+    }
+    let cost = |presses: &BitSet| presses.ones().map(|button| machine.buttons[button].cost).sum::<i64>();
+    let mut best = presses.clone();
+    for step in 1..1_u64 << free.len() {
+        if !meter.tick() {
+            return meter.exhausted();
+        }
+        presses ^= &nullspace[step.trailing_zeros() as usize];
+        if cost(&presses) < cost(&best) {
+            best.clone_from(&presses);
+        }
+    }
+    let presses = (0..num_buttons).map(|button| i64::from(best.get(button))).collect();
+    Outcome::Solved(PressPlan { total: cost(&best), presses })
+}
+
+/// The original breadth-first search over light states, kept to cross-check
+/// `min_presses`. It takes the cheapest state first rather than the fewest
+/// presses, which is the same thing unless buttons cost more than 1. Each
+/// state tried is a step of the budget.
+pub fn bfs_min_presses(machine: &Machine, meter: &mut Meter) -> Outcome {
+    if let Err(overflow) = machine.check_costs(1) {
+        return Outcome::Failed(overflow.to_string());
+    }
+    let start = BitSet::new(machine.light_target.len());
+    // The cheapest way to each state so far, and the last button pressed to get there.
+    let mut cheapest = HashMap::from([(start.clone(), (0, None))]);
+    let mut queue = PriorityQueue::new();
+    queue.push(start, Reverse(0));
+    while let Some((lights, Reverse(cost))) = queue.pop() {
+        if lights == machine.light_target {
+            return Outcome::Solved(retrace_presses(machine, &cheapest, lights));
+        }
+        if !meter.tick() {
+            return meter.exhausted();
+        }
+        for (index, button) in machine.buttons.iter().enumerate() {
+            let new_lights = press_button_on_lights(&lights, button);
+            let new_cost = cost + button.cost;
+            if cheapest.get(&new_lights).is_none_or(|&(cost, _)| new_cost < cost) {
+                cheapest.insert(new_lights.clone(), (new_cost, Some(index)));
+                queue.push(new_lights, Reverse(new_cost));
+            }
+        }
+    }
+    Outcome::Infeasible(Infeasibility::Unreachable { states: cheapest.len() as u64 })
+}
+
+/// Splits the buttons in two and lists the lights every subset of each half
+/// toggles, keeping the cheapest subset for each set of lights from the first
+/// half. A subset of the second half then needs exactly the first-half lights
+/// that make up the rest of the target, one lookup away. That is about
+/// `2 · 2^(n/2)` subsets for `n` buttons rather than the `2^n` states the
+/// breadth-first search may reach, and each is a step of the budget.
+pub fn meet_in_the_middle_min_presses(machine: &Machine, meter: &mut Meter) -> Outcome {
+    if let Err(overflow) = machine.check_costs(1) {
+        return Outcome::Failed(overflow.to_string());
+    }
+    let (first, second) = machine.buttons.split_at(machine.buttons.len() / 2);
+    if second.len() >= u64::BITS as usize {
+        // No budget stretches to 2^64 subsets, so there is no point starting.
+        return meter.exhausted();
+    }
+    let mut first_half: HashMap<BitSet, (i64, u64)> = HashMap::new();
+    for (lights, cost, pressed) in subset_lights(first, machine.light_target.len()) {
+        if !meter.tick() {
+            return meter.exhausted();
+        }
+        if first_half.get(&lights).is_none_or(|&(cheapest, _)| cost < cheapest) {
+            first_half.insert(lights, (cost, pressed));
+        }
+    }
+    let mut best: Option<(i64, u64, u64)> = None;
+    for (lights, cost, pressed) in subset_lights(second, machine.light_target.len()) {
+        if !meter.tick() {
+            return meter.exhausted();
+        }
+        let Some(&(first_cost, first_pressed)) = first_half.get(&(&lights ^ &machine.light_target)) else {
+            continue;
+        };
+        if best.is_none_or(|(cheapest, _, _)| first_cost + cost < cheapest) {
+            best = Some((first_cost + cost, first_pressed, pressed));
+        }
+    }
+    let Some((total, first_pressed, second_pressed)) = best else {
+        // Every combination was tried, so elimination will agree, and can say why.
+        return min_presses(machine, meter);
+    };
+    let pressed = |mask: u64, count: usize| (0..count).map(move |button| i64::from(mask >> button & 1 == 1));
+    let presses = pressed(first_pressed, first.len()).chain(pressed(second_pressed, second.len())).collect();
+    Outcome::Solved(PressPlan { total, presses })
+}
+
+/// The lights each subset of `buttons` toggles, what it costs, and which
+/// buttons it presses as a bitmask, one button flipped per subset in Gray
+/// code order. There must be fewer than 64 buttons.
+fn subset_lights(buttons: &[Button], num_lights: usize) -> impl Iterator<Item = (BitSet, i64, u64)> + '_ {
+    let mut lights = BitSet::new(num_lights);
+    let (mut cost, mut pressed) = (0, 0_u64);
+    let empty = (lights.clone(), cost, pressed);
+    std::iter::once(empty).chain((1..1_u64 << buttons.len()).map(move |step| {
+        let button = step.trailing_zeros() as usize;
+        pressed ^= 1 << button;
+        lights = press_button_on_lights(&lights, &buttons[button]);
+        cost += if pressed >> button & 1 == 1 { buttons[button].cost } else { -buttons[button].cost };
+        (lights.clone(), cost, pressed)
+    }))
+}
+
+/// Follows the last press into each state back from `lights` to all off.
+/// Pressing a button again undoes it, so that leads to the state before.
+fn retrace_presses(machine: &Machine, cheapest: &HashMap<BitSet, (i64, Option<usize>)>, mut lights: BitSet) -> PressPlan {
+    let mut presses = vec![0; machine.buttons.len()];
+    while let (_, Some(button)) = cheapest[&lights] {
+        presses[button] += 1;
+        lights = press_button_on_lights(&lights, &machine.buttons[button]);
+    }
+    PressPlan { total: machine.cost(&presses), presses }
+}
+
+pub(crate) fn press_button_on_lights(lights: &BitSet, button: &Button) -> BitSet {
+    let mut new_lights = lights.clone();
+    for &i in &button.toggle_indices {
+        new_lights.toggle(i);
+    }
+    new_lights
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Budget,
+        tests::{EXAMPLE, machines, random_machines},
+    };
+
+    type Solver = fn(&Machine, &mut Meter) -> Outcome;
+
+    const SOLVERS: [(&str, Solver); 3] =
+        [("elimination", min_presses), ("bfs", bfs_min_presses), ("meet in the middle", meet_in_the_middle_min_presses)];
+
+    /// The cheapest cost each solver finds, after checking its presses, or
+    /// `None` if it finds the lights out of reach.
+    fn costs(machine: &Machine) -> Vec<Option<i64>> {
+        SOLVERS
+            .iter()
+            .map(|(name, solve)| match solve(machine, &mut Meter::new(Budget::default())) {
+                Outcome::Solved(plan) => {
+                    machine.check_lights(&plan).unwrap_or_else(|err| panic!("{name} on {machine:?}: {err}"));
+                    Some(plan.total)
+                }
+                Outcome::Infeasible(_) => None,
+                outcome => panic!("{name} on {machine:?}: {outcome:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn solvers_agree_on_the_example() {
+        let costs: Vec<Vec<Option<i64>>> = machines(EXAMPLE).iter().map(costs).collect();
+        assert_eq!(costs, [[Some(2); 3], [Some(3); 3], [Some(2); 3]]);
+    }
+
+    #[test]
+    fn solvers_agree_on_random_machines() {
+        let mut unreachable = 0;
+        for machine in random_machines(300, 1) {
+            let costs = costs(&machine);
+            assert!(costs.iter().all(|&cost| cost == costs[0]), "{machine:?}: {costs:?}");
+            unreachable += usize::from(costs[0].is_none());
+        }
+        assert!((1..300).contains(&unreachable), "{unreachable} of the machines were unreachable");
+    }
+
+    #[test]
+    fn odd_lights_certify_the_target_is_unreachable() {
+        let machine = &machines("[##.#] (0,1) (1,2,3,0) (2,3) {1,1,1,1}")[0];
+        let Outcome::Infeasible(Infeasibility::OddLights(lights)) = min_presses(machine, &mut Meter::new(Budget::default())) else {
+            panic!("elimination found presses for {machine:?}");
+        };
+        for button in &machine.buttons {
+            let toggled = button.toggle_indices.iter().filter(|light| lights.contains(light)).count();
+            assert_eq!(toggled % 2, 0, "{button} toggles an odd number of {lights:?}");
+        }
+        assert_eq!(lights.iter().filter(|&&light| machine.light_target.get(light)).count() % 2, 1);
+    }
+}
@@ -0,0 +1,118 @@
+//! What a solver makes of one machine, and how much work it may do first.
+
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+/// The cheapest presses found for one part of a machine.
+#[derive(Debug)]
+pub struct PressPlan {
+    /// What the presses cost, which is how many there are unless some
+    /// buttons cost more than 1.
+    pub total: i64,
+    /// How many times to press each of the machine's buttons, in order.
+    pub presses: Vec<i64>,
+}
+
+#[derive(Debug)]
+pub enum Outcome {
+    Solved(PressPlan),
+    /// No presses reach the target.
+    Infeasible(Infeasibility),
+    /// The solver ran out of budget before it could tell.
+    BudgetExhausted { steps: u64, elapsed: Duration },
+    /// The solver could not work the machine out, such as when its arithmetic
+    /// would overflow.
+    Failed(String),
+}
+
+/// Why no presses reach a machine's target.
+#[derive(Debug)]
+pub enum Infeasibility {
+    /// Every button toggles an even number of these lights, so an even number
+    /// of them are always on, but the diagram has an odd number on.
+    OddLights(Vec<usize>),
+    /// `Σ multipliers[i] · joltage[i]` is unchanged by every button, so it
+    /// stays 0, but the target joltages make it `total`.
+    Inconsistent { multipliers: Vec<i64>, total: i64 },
+    /// The equations have solutions, but none with every button pressed a
+    /// whole, non-negative number of times.
+    NoNonNegativeSolution,
+    /// The search tried every state reachable without overshooting.
+    Unreachable { states: u64 },
+}
+
+impl Display for Infeasibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Infeasibility::OddLights(lights) => {
+                let lights: Vec<String> = lights.iter().map(usize::to_string).collect();
+                write!(f, "every button toggles an even number of lights {}, but an odd number must be on", lights.join(", "))
+            }
+            Infeasibility::Inconsistent { multipliers, total } => {
+                let terms: Vec<String> = multipliers
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &multiplier)| multiplier != 0)
+                    .map(|(counter, multiplier)| format!("{multiplier}·joltage[{counter}]"))
+                    .collect();
+                write!(f, "{} stays 0 whatever is pressed, but the target makes it {total}", terms.join(" + "))
+            }
+            Infeasibility::NoNonNegativeSolution => write!(f, "every solution presses some button a negative number of times"),
+            Infeasibility::Unreachable { states } => write!(f, "none of the {states} reachable states is the target"),
+        }
+    }
+}
+
+/// How much work a solver may do on one machine before it gives up. What a
+/// step is depends on the solver, but it is roughly one candidate tried.
+#[derive(Clone, Copy, Debug)]
+pub struct Budget {
+    pub max_steps: Option<u64>,
+    pub max_time: Option<Duration>,
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Budget { max_steps: Some(10_000_000), max_time: None }
+    }
+}
+
+/// Counts the steps a solver takes against its `Budget`.
+pub struct Meter {
+    budget: Budget,
+    started: Instant,
+    steps: u64,
+}
+
+/// How many steps to take between looks at the clock.
+const STEPS_PER_CLOCK_CHECK: u64 = 1024;
+
+impl Meter {
+    pub fn new(budget: Budget) -> Self {
+        Meter { budget, started: Instant::now(), steps: 0 }
+    }
+
+    /// Counts one more step, and returns whether the budget allows it.
+    pub fn tick(&mut self) -> bool {
+        self.steps += 1;
+        let too_many = self.budget.max_steps.is_some_and(|max_steps| self.steps > max_steps);
+        let too_long = self.steps.is_multiple_of(STEPS_PER_CLOCK_CHECK)
+            && self.budget.max_time.is_some_and(|max_time| self.started.elapsed() > max_time);
+        !(too_many || too_long)
+    }
+
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// The outcome of a solver that stopped because `tick` said to.
+    pub fn exhausted(&self) -> Outcome {
+        Outcome::BudgetExhausted { steps: self.steps, elapsed: self.started.elapsed() }
+    }
+}
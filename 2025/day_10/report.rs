@@ -0,0 +1,132 @@
+//! A row per machine of how each part went, for finding the inputs that make
+//! a solver struggle.
+
+use std::{
+    fmt::{self, Display},
+    io::{self, Write},
+    sync::Mutex,
+    time::Duration,
+};
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{Machine, Outcome};
+
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub enum ReportFormat {
+    /// One row per machine, with a header
+    #[default]
+    Csv,
+    /// An array with one object per machine
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    Lights,
+    Joltages,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", if *self == Part::Lights { "lights" } else { "joltages" })
+    }
+}
+
+/// Collects a `MachineRecord` per machine as the parts are solved. A part
+/// that was not run, because `--part` picked the other, is left empty.
+#[derive(Debug, Default)]
+pub struct Report {
+    machines: Mutex<Vec<MachineRecord>>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct MachineRecord {
+    pub line: usize,
+    pub buttons: usize,
+    pub counters: usize,
+    pub lights: Option<PartRecord>,
+    pub joltages: Option<PartRecord>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PartRecord {
+    /// `solved`, `infeasible`, `budget_exhausted` or `failed`.
+    pub outcome: &'static str,
+    /// What the cheapest presses cost, if the machine was solved. That is how
+    /// many there are unless buttons have costs.
+    pub min_presses: Option<i64>,
+    pub time_ns: u128,
+    /// How many steps of its budget the solver took, such as states expanded.
+    pub steps: u64,
+}
+
+impl PartRecord {
+    pub fn new(outcome: &Outcome, time: Duration, steps: u64) -> Self {
+        let (outcome, min_presses) = match outcome {
+            Outcome::Solved(plan) => ("solved", Some(plan.total)),
+            Outcome::Infeasible(_) => ("infeasible", None),
+            Outcome::BudgetExhausted { .. } => ("budget_exhausted", None),
+            Outcome::Failed(_) => ("failed", None),
+        };
+        PartRecord { outcome, min_presses, time_ns: time.as_nanos(), steps }
+    }
+}
+
+impl Report {
+    /// Records how `part` went for the machine on line `index + 1`.
+    pub(crate) fn record(&self, index: usize, machine: &Machine, part: Part, record: PartRecord) {
+        let mut machines = self.machines.lock().expect("another thread panicked while recording");
+        while machines.len() <= index {
+            let line = machines.len() + 1;
+            machines.push(MachineRecord { line, buttons: 0, counters: 0, lights: None, joltages: None });
+        }
+        let machine_record = &mut machines[index];
+        machine_record.buttons = machine.buttons.len();
+        machine_record.counters = machine.joltage_target.len();
+        match part {
+            Part::Lights => machine_record.lights = Some(record),
+            Part::Joltages => machine_record.joltages = Some(record),
+        }
+    }
+
+    /// Whether nothing was recorded, as when the input did not parse.
+    pub fn is_empty(&self) -> bool {
+        self.machines.lock().expect("another thread panicked while recording").is_empty()
+    }
+
+    pub fn machines(&self) -> Vec<MachineRecord> {
+        self.machines.lock().expect("another thread panicked while recording").clone()
+    }
+
+    pub fn write(&self, format: ReportFormat, mut writer: impl Write) -> io::Result<()> {
+        let machines = self.machines();
+        match format {
+            ReportFormat::Csv => {
+                let part_columns = |part: Part| format!("{part}_outcome,{part}_min_presses,{part}_time_ns,{part}_steps");
+                writeln!(writer, "line,buttons,counters,{},{}", part_columns(Part::Lights), part_columns(Part::Joltages))?;
+                for machine in &machines {
+                    let (lights, joltages) = (csv_fields(&machine.lights), csv_fields(&machine.joltages));
+                    writeln!(writer, "{},{},{},{lights},{joltages}", machine.line, machine.buttons, machine.counters)?;
+                }
+            }
+            ReportFormat::Json => {
+                serde_json::to_writer_pretty(&mut writer, &machines)?;
+                writeln!(writer)?;
+            }
+        }
+        writer.flush()
+    }
+}
+
+/// A part's four columns, all empty if it was not solved.
+fn csv_fields(record: &Option<PartRecord>) -> String {
+    match record {
+        Some(record) => {
+            let min_presses = record.min_presses.map(|presses| presses.to_string()).unwrap_or_default();
+            format!("{},{min_presses},{},{}", record.outcome, record.time_ns, record.steps)
+        }
+        None => ",,,".to_string(),
+    }
+}
@@ -0,0 +1,76 @@
+use std::{error::Error, ops::RangeInclusive};
+
+use aoc_common::{Answer, ParseError, RangeSet, Solution, parse_number};
+
+#[derive(Default)]
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = RangeSet;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_input(input.trim_end())?)
+    }
+
+    fn part_1(&self, ranges: &Self::Input) -> impl Answer {
+        sum_invalids_in_ranges(ranges, is_invalid_pt_1)
+    }
+
+    fn part_2(&self, ranges: &Self::Input) -> impl Answer {
+        sum_invalids_in_ranges(ranges, is_invalid_pt_2)
+    }
+}
+
+fn parse_input(line: &str) -> Result<RangeSet, ParseError> {
+    line.split(",").map(|range| parse_range(line, range)).collect()
+}
+
+fn parse_range(line: &str, range: &str) -> Result<RangeInclusive<i64>, ParseError> {
+    let Some((start, end)) = range.split_once("-") else {
+        return Err(ParseError::at(line, range, format!("expected a range like 11-22, found '{range}'")));
+    };
+    let first: i64 = parse_number(line, start)?;
+    if first < 1 {
+        return Err(ParseError::at(line, start, format!("product IDs start at 1, found {first}")));
+    }
+    Ok(first..=parse_number(line, end)?)
+}
+
+fn sum_invalids_in_ranges<F>(ranges: &RangeSet, invalidity_fn: F) -> i64 where F: Fn(i64) -> bool + Copy {
+    ranges.ranges().map(|r| sum_invalids_in_range(r, invalidity_fn)).sum()
+}
+
+fn sum_invalids_in_range<F>(range: RangeInclusive<i64>, invalidity_fn: F) -> i64 where F: Fn(i64) -> bool + Copy {
+    let mut sum = 0;
+    for i in range {
+        if invalidity_fn(i) {
+            sum += i;
+        }
+    }
+    sum
+}
+
+fn is_invalid_pt_1(num: i64) -> bool {
+    let num_digits = num.ilog10() + 1;
+    if !num_digits.is_multiple_of(2) {
+        return false;
+    }
+    let half_digits = num_digits / 2;
+    let first_n_digits = num / 10i64.pow(half_digits);
+    let last_n_digits = num % 10i64.pow(half_digits);
+    first_n_digits == last_n_digits
+}
+
+fn is_invalid_pt_2(num: i64) -> bool {
+    let num_digits = (num.ilog10() + 1) as usize;
+    let num_str = num.to_string();
+    for substr_len in 1..=num_digits / 2 {
+        if num_digits.is_multiple_of(substr_len) {
+            let first_piece: &str = &num_str[0..substr_len];
+            if first_piece.repeat(num_digits / substr_len) == num_str {
+                return true;
+            }
+        }
+    }
+    false
+}
@@ -0,0 +1,63 @@
+use std::error::Error;
+
+use aoc_common::{Answer, Fallible, ParseError, Solution, parse_lines};
+
+const PART_1_BATTERIES: usize = 3;
+const PART_2_BATTERIES: usize = 12;
+
+#[derive(Default)]
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_lines(input, parse_bank)?)
+    }
+
+    fn part_1(&self, banks: &Self::Input) -> impl Answer {
+        Fallible(sum_max_joltages(banks, PART_1_BATTERIES))
+    }
+
+    fn part_2(&self, banks: &Self::Input) -> impl Answer {
+        Fallible(sum_max_joltages(banks, PART_2_BATTERIES))
+    }
+}
+
+fn parse_bank(line: &str) -> Result<String, ParseError> {
+    if let Some((index, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        let bad = &line[index..index + c.len_utf8()];
+        return Err(ParseError::at(line, bad, format!("invalid battery '{c}', expected a digit")));
+    }
+    if line.is_empty() {
+        return Err(ParseError::line(line, "expected a bank of batteries, found an empty line"));
+    }
+    Ok(line.to_string())
+}
+
+/// Fails on a bank with fewer than `num_batteries` batteries. Each part turns
+/// on a different number, so the parser leaves this check to them.
+fn sum_max_joltages(banks: &[String], num_batteries: usize) -> Result<i64, String> {
+    if let Some((index, bank)) = banks.iter().enumerate().find(|(_, bank)| bank.len() < num_batteries) {
+        return Err(format!("the bank on line {} has {} batteries, but {num_batteries} must be turned on", index + 1, bank.len()));
+    }
+    Ok(banks.iter()
+        .map(|bank| get_max_joltage(bank, num_batteries))
+        .sum())
+}
+
+fn get_max_joltage(bank: &str, num_batteries: usize) -> i64 {
+    let chars = bank.chars();
+    if num_batteries == 1 {
+        return chars.max().unwrap().to_digit(10).unwrap() as i64;
+    }
+    let chars: Vec<char> = chars.collect();
+    let (mut max_joltage, mut max_joltage_index) = ('0', 0);
+    for (i, &c) in chars[..=chars.len() - num_batteries].iter().enumerate() {
+        if c > max_joltage {
+            (max_joltage, max_joltage_index) = (c, i);
+        }
+    }
+    let recursive_max_joltage = get_max_joltage(&bank[max_joltage_index + 1..], num_batteries - 1);
+    format!("{}{}", max_joltage, recursive_max_joltage).parse::<i64>().unwrap()
+}
@@ -0,0 +1,57 @@
+use std::error::Error;
+
+use aoc_common::{Answer, Grid, Position, Solution};
+
+#[derive(Default)]
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<bool>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(Grid::parse(input, parse_cell)?)
+    }
+
+    fn part_1(&self, rolls: &Self::Input) -> impl Answer {
+        let (_rolls, count) = remove_valid_rolls(rolls);
+        count
+    }
+
+    fn part_2(&self, rolls: &Self::Input) -> impl Answer {
+        let mut sum = 0;
+        let (mut new_rolls, mut removed_count) = remove_valid_rolls(rolls);
+        while removed_count > 0 {
+            sum += removed_count;
+            (new_rolls, removed_count) = remove_valid_rolls(&new_rolls);
+        }
+        sum
+    }
+}
+
+fn parse_cell(c: char) -> Result<bool, String> {
+    match c {
+        '.' => Ok(false),
+        '@' => Ok(true),
+        _ => Err(format!("invalid character '{c}', expected '.' or '@'")),
+    }
+}
+
+fn remove_valid_rolls(rolls: &Grid<bool>) -> (Grid<bool>, i32) {
+    let mut removed_count = 0;
+    let mut new_rolls = rolls.clone();
+    for position in rolls.positions() {
+        if is_valid_roll(rolls, position) {
+            removed_count += 1;
+            new_rolls[position] = false;
+        }
+    }
+    (new_rolls, removed_count)
+}
+
+fn is_valid_roll(rolls: &Grid<bool>, position: Position) -> bool {
+    if !rolls[position] {
+        return false;
+    }
+    let num_neighbor_rolls = rolls.neighbors8(position).filter(|&neighbor| rolls[neighbor]).count();
+    num_neighbor_rolls < 4
+}
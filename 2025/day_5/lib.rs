@@ -0,0 +1,45 @@
+use std::{error::Error, ops::RangeInclusive};
+
+use aoc_common::{Answer, ParseError, RangeSet, Solution, parse_number, split_sections};
+
+#[derive(Default)]
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (RangeSet, Vec<i64>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, (fresh_ids, values): &Self::Input) -> impl Answer {
+        values.iter().filter(|&&value| fresh_ids.contains(value)).count()
+    }
+
+    fn part_2(&self, (fresh_ids, _values): &Self::Input) -> impl Answer {
+        fresh_ids.cardinality()
+    }
+}
+
+fn parse_input(input: &str) -> Result<(RangeSet, Vec<i64>), ParseError> {
+    let sections = split_sections(input);
+    let [range_lines, value_lines] = sections.as_slice() else {
+        return Err(ParseError::at(input, &input[input.len()..], "expected ranges and values separated by a blank line"));
+    };
+    let fresh_ids = range_lines
+        .iter()
+        .map(|line| parse_range(line).map_err(|err| err.within(input, line)))
+        .collect::<Result<_, _>>()?;
+    let values = value_lines
+        .iter()
+        .map(|line| parse_number(line, line).map_err(|err| err.within(input, line)))
+        .collect::<Result<_, _>>()?;
+    Ok((fresh_ids, values))
+}
+
+fn parse_range(line: &str) -> Result<RangeInclusive<i64>, ParseError> {
+    let Some((min, max)) = line.split_once('-') else {
+        return Err(ParseError::line(line, format!("expected a range like 3-5, found '{line}'")));
+    };
+    Ok(parse_number(line, min)?..=parse_number(line, max)?)
+}
@@ -0,0 +1,128 @@
+use std::{error::Error, ops::Range};
+
+use aoc_common::{Answer, Fallible, Operator, ParseError, Solution, parse_number};
+
+#[derive(Default)]
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Problem>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_problems(input)?)
+    }
+
+    fn part_1(&self, problems: &Self::Input) -> impl Answer {
+        Fallible(sum_expression_results(problems, |problem| &problem.human_operands))
+    }
+
+    fn part_2(&self, problems: &Self::Input) -> impl Answer {
+        Fallible(sum_expression_results(problems, |problem| &problem.cephalopod_operands))
+    }
+}
+
+fn sum_expression_results<F>(problems: &[Problem], operands: F) -> Result<i64, String> where F: Fn(&Problem) -> &[i64] {
+    problems.iter().enumerate().try_fold(0_i64, |sum, (index, problem)| {
+        let result = problem.operator.fold(operands(problem)).ok_or_else(|| format!("problem {} overflows an i64", index + 1))?;
+        sum.checked_add(result).ok_or_else(|| "the problems' results add up past an i64".to_string())
+    })
+}
+
+/// One column block of the worksheet, with its operands read both ways and
+/// the operator underneath them.
+#[derive(Debug)]
+pub struct Problem {
+    /// Each row of digits read left to right, ignoring alignment spaces.
+    human_operands: Vec<i64>,
+    /// Each column of digits read top to bottom, ignoring alignment spaces.
+    cephalopod_operands: Vec<i64>,
+    operator: Operator,
+}
+
+fn parse_problems(input: &str) -> Result<Vec<Problem>, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let Some((operator_line, operand_lines)) = lines.split_last() else {
+        return Err(ParseError::at(input, input, "expected rows of numbers followed by a row of operators"));
+    };
+    for line in operand_lines {
+        if let Some((index, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_digit() && *c != ' ') {
+            let bad = &line[index..index + c.len_utf8()];
+            return Err(ParseError::at(input, bad, format!("invalid character '{c}', expected a digit or a space")));
+        }
+    }
+    let operators = parse_operators(input, operator_line)?;
+    let blocks = split_into_blocks(operand_lines);
+    if blocks.len() != operators.len() {
+        let message = format!("found {} operators for {} columns of numbers", operators.len(), blocks.len());
+        return Err(ParseError::at(input, operator_line, message));
+    }
+    blocks
+        .into_iter()
+        .zip(operators)
+        .map(|(columns, operator)| {
+            Ok(Problem {
+                human_operands: parse_human_operands(input, operand_lines, &columns)?,
+                cephalopod_operands: parse_cephalopod_operands(input, operand_lines, columns)?,
+                operator,
+            })
+        })
+        .collect()
+}
+
+/// The columns of each block, splitting the operand lines at every column
+/// that is blank in all of them. The lines must be ASCII, so columns are
+/// byte offsets.
+fn split_into_blocks(lines: &[&str]) -> Vec<Range<usize>> {
+    let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+    let is_blank_column = |col: usize| lines.iter().all(|line| line.as_bytes().get(col).is_none_or(|&c| c == b' '));
+    let mut blocks = vec![];
+    let mut block_start = 0;
+    for col in 0..=width {
+        if col == width || is_blank_column(col) {
+            if col > block_start {
+                blocks.push(block_start..col);
+            }
+            block_start = col + 1;
+        }
+    }
+    blocks
+}
+
+/// The part of `line` in `columns`, which is shorter or empty where the line
+/// ends early.
+fn column_slice<'a>(line: &'a str, columns: &Range<usize>) -> &'a str {
+    &line[columns.start.min(line.len())..columns.end.min(line.len())]
+}
+
+fn parse_human_operands(input: &str, lines: &[&str], columns: &Range<usize>) -> Result<Vec<i64>, ParseError> {
+    lines
+        .iter()
+        .map(|line| {
+            let row = column_slice(line, columns);
+            if row.trim().is_empty() {
+                return Err(ParseError::at(input, row, "expected a number in every row of the problem"));
+            }
+            parse_number(input, row.trim())
+        })
+        .collect()
+}
+
+fn parse_operators(input: &str, line: &str) -> Result<Vec<Operator>, ParseError> {
+    line.split_whitespace()
+        .map(|operator| parse_operator(input, operator))
+        .collect()
+}
+
+fn parse_operator(input: &str, operator: &str) -> Result<Operator, ParseError> {
+    operator.parse().map_err(|message| ParseError::at(input, operator, message))
+}
+
+fn parse_cephalopod_operands(input: &str, lines: &[&str], columns: Range<usize>) -> Result<Vec<i64>, ParseError> {
+    columns
+        .map(|col| {
+            let digits: Vec<&str> = lines.iter().filter_map(|line| line.get(col..col + 1)).filter(|&c| c != " ").collect();
+            // Blocks have no blank columns, so every column has a digit to point at.
+            digits.concat().parse().map_err(|_| ParseError::at(input, digits[0], format!("the number read down from here, {}, overflows an i64", digits.concat())))
+        })
+        .collect()
+}
@@ -0,0 +1,95 @@
+use std::{collections::HashMap, error::Error, fmt::Display};
+
+use aoc_common::{Answer, Grid, ParseError, Solution};
+
+#[derive(Default)]
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Manifold;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_manifold(input)?)
+    }
+
+    fn part_1(&self, manifold: &Self::Input) -> impl Answer {
+        solve(manifold).num_splits
+    }
+
+    fn part_2(&self, manifold: &Self::Input) -> impl Answer {
+        solve(manifold).num_timelines
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Start,
+    Empty,
+    Splitter,
+}
+
+pub type Manifold = Grid<Cell>;
+
+fn parse_manifold(input: &str) -> Result<Manifold, ParseError> {
+    let manifold = Grid::parse(input, |c| match c {
+        'S' => Ok(Cell::Start),
+        '.' => Ok(Cell::Empty),
+        '^' => Ok(Cell::Splitter),
+        _ => Err(format!("invalid character '{c}', expected 'S', '.' or '^'")),
+    })?;
+    if manifold.find(&Cell::Start).is_none() {
+        return Err(ParseError::at(input, input, "expected the beam's start 'S'"));
+    }
+    Ok(manifold)
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Cell::Start => write!(f, "S"),
+            Cell::Empty => write!(f, "."),
+            Cell::Splitter => write!(f, "^"),
+        }
+    }
+}
+
+struct BeamCounts {
+    num_splits: i64,
+    num_timelines: i64,
+}
+
+fn solve(manifold: &Manifold) -> BeamCounts {
+    let (start_row, start_col) = manifold.find(&Cell::Start).unwrap();
+    let mut beam_index_to_num_paths = HashMap::from([(start_col, 1)]);
+    let mut num_splits = 0;
+    // Beams split off either side of the manifold leave it, but still end a timeline.
+    let mut num_escaped_paths = 0;
+    for row in manifold.rows().skip(start_row + 1) {
+        let mut new_beam_indices = HashMap::new();
+        for (index, num_paths) in beam_index_to_num_paths.into_iter() {
+            match row[index] {
+                Cell::Splitter => {
+                    for side in [index.checked_sub(1), Some(index + 1).filter(|&i| i < row.len())] {
+                        match side {
+                            Some(side) => add_num_paths_to_index(&mut new_beam_indices, side, num_paths),
+                            None => num_escaped_paths += num_paths,
+                        }
+                    }
+                    num_splits += 1;
+                },
+                _ => {add_num_paths_to_index(&mut new_beam_indices, index, num_paths);}
+            }
+        }
+        beam_index_to_num_paths = new_beam_indices;
+    }
+    let num_timelines = beam_index_to_num_paths.values().sum::<i64>() + num_escaped_paths;
+    BeamCounts {
+        num_splits,
+        num_timelines
+    }
+
+}
+
+fn add_num_paths_to_index(map: &mut HashMap<usize, i64>, index_to_modify: usize, num_paths: i64) {
+    map.entry(index_to_modify).and_modify(|existing_paths| *existing_paths += num_paths).or_insert(num_paths);
+}
@@ -0,0 +1,84 @@
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+
+use crate::paths::answers_path;
+
+/// The answers already accepted for one year's puzzles, as stored in
+/// `<year>/answers.toml`. A day can have entries for several inputs, told apart
+/// by `input_hash`; an entry without one applies to any input.
+#[derive(Default, Serialize, Deserialize)]
+pub struct AnswerBook {
+    #[serde(default)]
+    answers: Vec<KnownAnswers>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct KnownAnswers {
+    pub day: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_hash: Option<String>,
+}
+
+impl KnownAnswers {
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            _ => self.part_2.as_deref(),
+        }
+    }
+}
+
+impl AnswerBook {
+    pub fn load(year: u16) -> Result<AnswerBook, String> {
+        let path = answers_path(year);
+        match fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents).map_err(|err| format!("invalid answers {}: {err}", path.display())),
+            Err(_) => Ok(AnswerBook::default()),
+        }
+    }
+
+    pub fn save(&mut self, year: u16) -> Result<(), String> {
+        let path = answers_path(year);
+        self.answers.sort_by_key(|known| known.day);
+        let contents = toml::to_string(self).map_err(|err| err.to_string())?;
+        fs::write(&path, contents).map_err(|err| format!("could not write {}: {err}", path.display()))
+    }
+
+    /// The answers for a day's input, preferring an entry recorded for exactly
+    /// this input over one that applies to any input.
+    pub fn lookup(&self, day: u8, input_hash: &str) -> Option<&KnownAnswers> {
+        let for_day = || self.answers.iter().filter(move |known| known.day == day);
+        for_day()
+            .find(|known| known.input_hash.as_deref() == Some(input_hash))
+            .or_else(|| for_day().find(|known| known.input_hash.is_none()))
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: &str, input_hash: &str) {
+        let index = match self.answers.iter().position(|known| known.day == day && known.input_hash.as_deref() == Some(input_hash)) {
+            Some(index) => index,
+            None => {
+                self.answers.push(KnownAnswers { day, part_1: None, part_2: None, input_hash: Some(input_hash.to_string()) });
+                self.answers.len() - 1
+            }
+        };
+        let known = &mut self.answers[index];
+        let slot = if part == 1 { &mut known.part_1 } else { &mut known.part_2 };
+        *slot = Some(answer.to_string());
+    }
+}
+
+/// A stable fingerprint of an input (64-bit FNV-1a), so answers can be tied to
+/// the input they were accepted for.
+pub fn input_hash(input: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in input.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{hash:016x}")
+}
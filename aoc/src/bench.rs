@@ -0,0 +1,121 @@
+use std::{
+    error::Error,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common::{Puzzle, Timings};
+use serde::Serialize;
+
+use crate::{catch_panics, paths::read_input, registry};
+
+#[derive(Serialize)]
+struct Report {
+    generated_at: u64,
+    runs: u32,
+    warmup: u32,
+    results: Vec<PuzzleResult>,
+}
+
+#[derive(Serialize)]
+struct PuzzleResult {
+    year: u16,
+    day: u8,
+    #[serde(flatten)]
+    outcome: Outcome,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum Outcome {
+    Timed { parse: Stats, part_1: Stats, part_2: Stats },
+    Failed { error: String },
+}
+
+#[derive(Serialize)]
+struct Stats {
+    min_ns: u128,
+    median_ns: u128,
+    mean_ns: u128,
+}
+
+/// Solves each selected day `warmup` times untimed and then `runs` times
+/// timed, and reports the spread of each stage's times.
+pub fn bench(year: Option<u16>, day: Option<u8>, runs: u32, warmup: u32, json: bool) -> Result<(), Box<dyn Error>> {
+    let results: Vec<PuzzleResult> = registry::select(year, day)?
+        .into_iter()
+        .map(|puzzle| PuzzleResult {
+            year: puzzle.year,
+            day: puzzle.day,
+            outcome: match time_puzzle(puzzle, runs, warmup) {
+                Ok(timings) => Outcome::Timed {
+                    parse: Stats::of(timings.iter().map(|t| t.parse)),
+                    part_1: Stats::of(timings.iter().map(|t| t.part_1)),
+                    part_2: Stats::of(timings.iter().map(|t| t.part_2)),
+                },
+                Err(error) => Outcome::Failed { error },
+            },
+        })
+        .collect();
+    if json {
+        let generated_at = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let report = Report { generated_at, runs, warmup, results };
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_table(&results);
+    }
+    Ok(())
+}
+
+fn time_puzzle(puzzle: &Puzzle, runs: u32, warmup: u32) -> Result<Vec<Timings>, String> {
+    let raw_input = read_input(puzzle.year, puzzle.day)?;
+    for _ in 0..warmup {
+        catch_panics(|| puzzle.solve_timed(&raw_input))?;
+    }
+    (0..runs)
+        .map(|_| catch_panics(|| puzzle.solve_timed(&raw_input)).map(|(_, timings)| timings))
+        .collect()
+}
+
+impl Stats {
+    fn of(durations: impl Iterator<Item = Duration>) -> Stats {
+        let mut nanos: Vec<u128> = durations.map(|d| d.as_nanos()).collect();
+        nanos.sort();
+        Stats {
+            min_ns: nanos[0],
+            median_ns: median(&nanos),
+            mean_ns: nanos.iter().sum::<u128>() / nanos.len() as u128,
+        }
+    }
+}
+
+/// The middle of sorted `nanos`, or the mean of the two middle ones when
+/// there is an even number.
+fn median(nanos: &[u128]) -> u128 {
+    let middle = nanos.len() / 2;
+    if nanos.len().is_multiple_of(2) { (nanos[middle - 1] + nanos[middle]) / 2 } else { nanos[middle] }
+}
+
+fn print_table(results: &[PuzzleResult]) {
+    println!("{:<12}  {:<6}  {:>12}  {:>12}  {:>12}", "Day", "Stage", "Min", "Median", "Mean");
+    for result in results {
+        let name = format!("{} day {}", result.year, result.day);
+        match &result.outcome {
+            Outcome::Timed { parse, part_1, part_2 } => {
+                for (index, (stage, stats)) in [("parse", parse), ("part 1", part_1), ("part 2", part_2)].into_iter().enumerate() {
+                    let name = if index == 0 { name.as_str() } else { "" };
+                    println!(
+                        "{name:<12}  {stage:<6}  {:>12}  {:>12}  {:>12}",
+                        format_nanos(stats.min_ns),
+                        format_nanos(stats.median_ns),
+                        format_nanos(stats.mean_ns)
+                    );
+                }
+            }
+            Outcome::Failed { error } => println!("{name:<12}  failed: {error}"),
+        }
+    }
+}
+
+fn format_nanos(nanos: u128) -> String {
+    format!("{:.2?}", Duration::from_nanos(nanos as u64))
+}
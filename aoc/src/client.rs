@@ -0,0 +1,197 @@
+use std::{fmt, io::Read};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+
+const USER_AGENT: &str = "github.com/aantenberg/advent_of_code by aantenberg";
+
+/// A logged-in connection to the Advent of Code site (or whatever `base_url`
+/// points at).
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Client, String> {
+        Ok(Client {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: config.base_url().to_string(),
+            session: config.session()?.to_string(),
+        })
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|err| describe_error(&url, err))?;
+        read_body(response)
+    }
+
+    /// Posts an answer and returns how the site judged it, along with the
+    /// site's message.
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<(Verdict, String), String> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|err| describe_error(&url, err))?;
+        let message = article_text(&read_body(response)?);
+        Ok((Verdict::from_message(&message), message))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about which direction.
+    Wrong,
+    RateLimited,
+    /// The part is already solved, or not unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    fn from_message(message: &str) -> Verdict {
+        if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("too high") {
+            Verdict::TooHigh
+        } else if message.contains("too low") {
+            Verdict::TooLow
+        } else if message.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if message.contains("You gave an answer too recently") {
+            Verdict::RateLimited
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// Whether the site actually checked the answer.
+    pub fn is_judgement(self) -> bool {
+        matches!(self, Verdict::Correct | Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited => write!(f, "rate limited"),
+            Verdict::WrongLevel => write!(f, "already solved or locked"),
+            Verdict::Unknown => write!(f, "unrecognized response"),
+        }
+    }
+}
+
+/// The plain text of the page's `<article>`, which is where the site explains
+/// its verdict.
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn read_body(response: ureq::Response) -> Result<String, String> {
+    let mut body = String::new();
+    response
+        .into_reader()
+        .read_to_string(&mut body)
+        .map_err(|err| format!("could not read response: {err}"))?;
+    Ok(body)
+}
+
+fn describe_error(url: &str, err: ureq::Error) -> String {
+    match err {
+        ureq::Error::Status(400, _) => format!("{url} rejected the session token, it has probably expired"),
+        ureq::Error::Status(404, _) => format!("{url} was not found, the puzzle may not be unlocked yet"),
+        ureq::Error::Status(code, _) => format!("{url} returned HTTP {code}"),
+        ureq::Error::Transport(transport) => format!("could not reach {url}: {transport}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub_server::StubServer;
+
+    #[test]
+    fn recognizes_the_sites_verdicts() {
+        let cases = [
+            ("That's the right answer! You are one gold star closer to decorating the North Pole.", Verdict::Correct),
+            ("That's not the right answer; your answer is too high. If you're stuck, ...", Verdict::TooHigh),
+            ("That's not the right answer; your answer is too low. Please wait one minute.", Verdict::TooLow),
+            ("That's not the right answer. If you're stuck, make sure you're using the full input data.", Verdict::Wrong),
+            ("You gave an answer too recently; you have to wait after submitting an answer. You have 38s left to wait.", Verdict::RateLimited),
+            ("You don't seem to be solving the right level. Did you already complete it?", Verdict::WrongLevel),
+            ("Please log in to submit answers.", Verdict::Unknown),
+        ];
+        for (message, verdict) in cases {
+            assert_eq!(Verdict::from_message(message), verdict, "{message}");
+        }
+        assert!(Verdict::TooLow.is_judgement());
+        assert!(!Verdict::RateLimited.is_judgement());
+    }
+
+    #[test]
+    fn article_text_keeps_only_the_articles_words() {
+        let html = "<html><head><title>Day 1</title></head><body><main>\n\
+            <article><p>That's the <em>right</em> answer!\n  You are <a href=\"/2025\">one gold star</a> closer.</p></article>\n\
+            <p>You can [Return to Day 1].</p></main></body></html>";
+        assert_eq!(article_text(html), "That's the right answer! You are one gold star closer.");
+        // Without an article the whole page is used.
+        assert_eq!(article_text("<p>Please   log in.</p>"), "Please log in.");
+    }
+
+    #[test]
+    fn submits_the_part_and_answer_as_a_form() {
+        let page = "<main><article><p>That's not the right answer; your answer is too high.</p></article></main>";
+        let server = StubServer::start(200, page);
+        let client = Client::new(&server.config()).unwrap();
+
+        let (verdict, message) = client.submit(2025, 4, 2, "1234").unwrap();
+        assert_eq!(verdict, Verdict::TooHigh);
+        assert_eq!(message, "That's not the right answer; your answer is too high.");
+        let requests = server.requests();
+        assert_eq!(requests[0].line, "POST /2025/day/4/answer");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=stub-session"));
+        assert_eq!(requests[0].body, "level=2&answer=1234");
+    }
+
+    #[test]
+    fn explains_an_expired_session() {
+        let server = StubServer::start(400, "");
+        let err = Client::new(&server.config()).unwrap().input(2025, 1).unwrap_err();
+        assert!(err.ends_with("rejected the session token, it has probably expired"), "{err}");
+    }
+}
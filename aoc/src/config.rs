@@ -0,0 +1,61 @@
+use std::{env, fs, io, path::PathBuf};
+
+use serde::Deserialize;
+
+use crate::paths::workspace_root;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const SESSION_VAR: &str = "AOC_SESSION";
+const BASE_URL_VAR: &str = "AOC_BASE_URL";
+const CONFIG_VAR: &str = "AOC_CONFIG";
+
+/// Settings for talking to the Advent of Code site, read from
+/// `.aoc/config.toml` (or the file named by `AOC_CONFIG`) and overridden by
+/// the `AOC_SESSION` and `AOC_BASE_URL` environment variables.
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    /// Only a missing `.aoc/config.toml` falls back to the defaults. A file
+    /// named by `AOC_CONFIG` has to exist, and any other read error is returned.
+    pub fn load() -> Result<Config, String> {
+        let path = config_path();
+        let mut config: Config = match fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents).map_err(|err| format!("invalid config {}: {err}", path.display()))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound && env::var_os(CONFIG_VAR).is_none() => Config::default(),
+            Err(err) => return Err(format!("could not read config {}: {err}", path.display())),
+        };
+        if let Ok(session) = env::var(SESSION_VAR) {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var(BASE_URL_VAR) {
+            config.base_url = Some(base_url);
+        }
+        Ok(config)
+    }
+
+    pub fn session(&self) -> Result<&str, String> {
+        self.session
+            .as_deref()
+            .map(str::trim)
+            .filter(|session| !session.is_empty())
+            .ok_or(format!("no session token: set {SESSION_VAR} or add `session = \"...\"` to {}", config_path().display()))
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL).trim_end_matches('/')
+    }
+}
+
+fn config_path() -> PathBuf {
+    env::var_os(CONFIG_VAR).map(PathBuf::from).unwrap_or_else(|| local_dir().join("config.toml"))
+}
+
+/// Git-ignored directory for the config file and everything cached from the site.
+pub fn local_dir() -> PathBuf {
+    workspace_root().join(".aoc")
+}
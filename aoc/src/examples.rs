@@ -0,0 +1,103 @@
+use std::{error::Error, fs, path::PathBuf};
+
+use aoc_common::{Puzzle, with_file};
+use serde::Deserialize;
+
+use crate::{catch_panics, paths::day_dir, registry};
+
+const EXAMPLES_DIR: &str = "examples";
+
+/// The answers an example input should give, read from the `.toml` file next
+/// to it. Either part can be left out, since puzzles often use a different
+/// example for each part.
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expected {
+    part_1: Option<toml::Value>,
+    part_2: Option<toml::Value>,
+}
+
+impl Expected {
+    fn part(&self, part: u8) -> Option<String> {
+        let value = if part == 1 { &self.part_1 } else { &self.part_2 };
+        value.as_ref().map(|value| match value {
+            toml::Value::String(answer) => answer.clone(),
+            other => other.to_string(),
+        })
+    }
+}
+
+/// Runs every example input of the selected days through its solution and
+/// checks each part that has an expected answer.
+pub fn test_examples(year: Option<u16>, day: Option<u8>) -> Result<(), Box<dyn Error>> {
+    let (mut passed, mut failed) = (0, 0);
+    for puzzle in registry::select(year, day)? {
+        let name = format!("{} day {}", puzzle.year, puzzle.day);
+        let examples = find_examples(puzzle);
+        if examples.is_empty() {
+            println!("{name:<12}  no examples");
+        }
+        for input_path in examples {
+            let example = input_path.file_stem().unwrap().to_string_lossy().into_owned();
+            let (raw_input, expected) = match load_example(&input_path) {
+                Ok(loaded) => loaded,
+                Err(err) => {
+                    println!("{name:<12}  {example:<10}  FAIL    {err}");
+                    failed += 1;
+                    continue;
+                }
+            };
+            let parts: Vec<(u8, String)> = (1..=2).filter_map(|part| expected.part(part).map(|answer| (part, answer))).collect();
+            if parts.is_empty() {
+                println!("{name:<12}  {example:<10}  no expected answers");
+            }
+            for (part, expected) in parts {
+                let solve = || puzzle.solve_part(&raw_input, part).map_err(|err| with_file(err, &input_path.display().to_string()));
+                let outcome = match catch_panics(solve) {
+                    Ok(actual) if actual == expected => Ok(actual),
+                    Ok(actual) => Err(format!("expected {expected}, got {actual}")),
+                    Err(err) => Err(err),
+                };
+                match outcome {
+                    Ok(actual) => {
+                        println!("{name:<12}  {example:<10}  part {part}  pass  {actual}");
+                        passed += 1;
+                    }
+                    Err(err) => {
+                        println!("{name:<12}  {example:<10}  part {part}  FAIL  {err}");
+                        failed += 1;
+                    }
+                }
+            }
+        }
+    }
+    println!("\n{passed} passed, {failed} failed");
+    if failed > 0 {
+        return Err(format!("{failed} example(s) failed").into());
+    }
+    Ok(())
+}
+
+/// The day's `examples/*.txt` files, in name order.
+fn find_examples(puzzle: &Puzzle) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(day_dir(puzzle.year, puzzle.day).join(EXAMPLES_DIR)) else {
+        return vec![];
+    };
+    let mut examples: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    examples.sort();
+    examples
+}
+
+fn load_example(input_path: &PathBuf) -> Result<(String, Expected), String> {
+    let raw_input = fs::read_to_string(input_path).map_err(|err| format!("could not read {}: {err}", input_path.display()))?;
+    let expected_path = input_path.with_extension("toml");
+    let expected = match fs::read_to_string(&expected_path) {
+        Ok(contents) => toml::from_str(&contents).map_err(|err| format!("invalid {}: {err}", expected_path.display()))?,
+        Err(_) => Expected::default(),
+    };
+    Ok((raw_input, expected))
+}
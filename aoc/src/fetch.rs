@@ -0,0 +1,113 @@
+use std::{error::Error, fs, path::Path, path::PathBuf};
+
+use crate::{client::Client, config::Config, config::local_dir, paths::input_path};
+
+/// Puts the day's puzzle input in place, downloading it only if neither the
+/// day's directory nor the cache already has it.
+pub fn fetch_input(year: u16, day: u8) -> Result<(), Box<dyn Error>> {
+    fetch_into(year, day, &input_path(year, day), &cache_path(year, day), || Ok(Client::new(&Config::load()?)?))
+}
+
+/// `fetch_input` with the paths spelled out, connecting only if it has to
+/// download.
+fn fetch_into(
+    year: u16,
+    day: u8,
+    input_path: &Path,
+    cache_path: &Path,
+    connect: impl FnOnce() -> Result<Client, Box<dyn Error>>,
+) -> Result<(), Box<dyn Error>> {
+    if has_contents(input_path) {
+        println!("{} already exists", input_path.display());
+        return Ok(());
+    }
+    if !input_path.parent().is_some_and(|dir| dir.is_dir()) {
+        return Err(format!("{year} day {day} has not been set up, run `aoc new {year} {day}` first").into());
+    }
+    if has_contents(cache_path) {
+        fs::copy(cache_path, input_path)?;
+        println!("Copied cached input to {}", input_path.display());
+        return Ok(());
+    }
+    let input = connect()?.input(year, day)?;
+    fs::create_dir_all(cache_path.parent().unwrap())?;
+    fs::write(cache_path, &input)?;
+    fs::write(input_path, &input)?;
+    println!("Downloaded input to {}", input_path.display());
+    Ok(())
+}
+
+fn cache_path(year: u16, day: u8) -> PathBuf {
+    local_dir().join("cache").join(year.to_string()).join(format!("day_{day}.txt"))
+}
+
+/// An empty input file is just the placeholder `aoc new` leaves behind.
+fn has_contents(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+    use crate::stub_server::StubServer;
+
+    /// A fresh directory under the system's temporary directory.
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-{name}-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("day")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn downloads_once_then_uses_the_cache() {
+        let server = StubServer::start(200, "1\n2\n3\n");
+        let dir = scratch_dir("fetch");
+        let input_path = dir.join("day/input.txt");
+        let cache_path = dir.join("cache/2025/day_1.txt");
+        let connect = || Ok(Client::new(&server.config())?);
+
+        fetch_into(2025, 1, &input_path, &cache_path, connect).unwrap();
+        assert_eq!(fs::read_to_string(&input_path).unwrap(), "1\n2\n3\n");
+        assert_eq!(fs::read_to_string(&cache_path).unwrap(), "1\n2\n3\n");
+        {
+            let requests = server.requests();
+            assert_eq!(requests.len(), 1);
+            assert_eq!(requests[0].line, "GET /2025/day/1/input");
+            assert_eq!(requests[0].cookie.as_deref(), Some("session=stub-session"));
+        }
+
+        // An input that is already there, or only the empty placeholder with
+        // the cache filled, never reaches the server.
+        fetch_into(2025, 1, &input_path, &cache_path, || panic!("input.txt was downloaded again")).unwrap();
+        fs::write(&input_path, "").unwrap();
+        fetch_into(2025, 1, &input_path, &cache_path, || panic!("the cache was not used")).unwrap();
+        assert_eq!(fs::read_to_string(&input_path).unwrap(), "1\n2\n3\n");
+        assert_eq!(server.requests().len(), 1);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn a_failed_download_writes_nothing() {
+        let server = StubServer::start(404, "Not Found");
+        let dir = scratch_dir("fetch-missing");
+        let input_path = dir.join("day/input.txt");
+        let cache_path = dir.join("cache/2025/day_12.txt");
+
+        let err = fetch_into(2025, 12, &input_path, &cache_path, || Ok(Client::new(&server.config())?)).unwrap_err();
+        assert!(err.to_string().contains("was not found"), "{err}");
+        assert!(!input_path.exists() && !cache_path.exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn refuses_a_day_that_is_not_set_up() {
+        let dir = scratch_dir("fetch-unset");
+        let input_path = dir.join("missing/input.txt");
+        let err = fetch_into(2025, 3, &input_path, &dir.join("cache.txt"), || panic!("connected")).unwrap_err();
+        assert!(err.to_string().contains("has not been set up"), "{err}");
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,161 @@
+mod answers;
+mod bench;
+mod client;
+mod config;
+mod examples;
+mod fetch;
+mod paths;
+mod registry;
+mod setup;
+mod submit;
+#[cfg(test)]
+mod stub_server;
+mod verify;
+
+use std::{
+    error::Error,
+    panic::{self, AssertUnwindSafe},
+    process::ExitCode,
+};
+
+use aoc_common::{Puzzle, STDIN_PATH, print_answer, print_answers, read_input, with_file};
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code solutions in this repo")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every registered day of a year with --all
+    Run {
+        year: u16,
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+        /// Solve this file, or - for stdin, instead of the day's input.txt
+        #[arg(long, conflicts_with = "all")]
+        input: Option<String>,
+        /// Only solve this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+    },
+    /// Scaffold a new day and register it with the workspace and the runner
+    New {
+        year: u16,
+        day: u8,
+        /// Which file in templates/ to start from: lines, grid, sections, csv, columns or your own
+        #[arg(long, default_value = setup::DEFAULT_TEMPLATE)]
+        template: String,
+    },
+    /// Download a day's puzzle input, unless it is already on disk or cached
+    Fetch { year: u16, day: u8 },
+    /// Solve one part of a day and submit the answer, unless the submission
+    /// history already rules it out
+    Submit {
+        year: u16,
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Check every registered day (or just one year or day) against the
+    /// answers recorded in <year>/answers.toml
+    Verify { year: Option<u16>, day: Option<u8> },
+    /// Run every example input in <year>/day_<day>/examples through its day
+    /// and check the answers in the .toml file next to it
+    Test { year: Option<u16>, day: Option<u8> },
+    /// Time the parse, part 1 and part 2 stages of every registered day (or
+    /// just one year or day)
+    Bench {
+        year: Option<u16>,
+        day: Option<u8>,
+        /// Timed runs per day
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// Untimed runs per day before the timed ones
+        #[arg(long, default_value_t = 1)]
+        warmup: u32,
+        /// Print the results as JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run { year, day, all, input, part } => run(year, day, all, input.as_deref(), part),
+        Command::New { year, day, template } => setup::new_day(year, day, &template),
+        Command::Fetch { year, day } => fetch::fetch_input(year, day),
+        Command::Submit { year, day, part } => submit::submit(year, day, part),
+        Command::Verify { year, day } => verify::verify(year, day),
+        Command::Test { year, day } => examples::test_examples(year, day),
+        Command::Bench { year, day, runs, warmup, json } => bench::bench(year, day, runs, warmup, json),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(year: u16, day: Option<u8>, all: bool, input_path: Option<&str>, part: Option<u8>) -> Result<(), Box<dyn Error>> {
+    let puzzles: Vec<&Puzzle> = match day {
+        Some(day) => vec![registry::get(year, day)?],
+        None => registry::for_year(year).collect(),
+    };
+    if puzzles.is_empty() {
+        return Err(format!("no solutions registered for {year}").into());
+    }
+    let mut failures = 0;
+    for puzzle in puzzles {
+        if all {
+            println!("== {} day {}", puzzle.year, puzzle.day);
+        }
+        if let Err(err) = run_puzzle(puzzle, input_path, part) {
+            eprintln!("{} day {}: {err}", puzzle.year, puzzle.day);
+            failures += 1;
+        }
+    }
+    if failures > 0 {
+        return Err(format!("{failures} day(s) failed").into());
+    }
+    Ok(())
+}
+
+fn run_puzzle(puzzle: &Puzzle, input_path: Option<&str>, part: Option<u8>) -> Result<(), Box<dyn Error>> {
+    let (raw_input, file) = match input_path {
+        Some(STDIN_PATH) => (read_input(STDIN_PATH)?, "<stdin>".to_string()),
+        Some(path) => (read_input(path)?, path.to_string()),
+        None => (paths::read_input(puzzle.year, puzzle.day)?, paths::input_path(puzzle.year, puzzle.day).display().to_string()),
+    };
+    match part {
+        Some(part) => print_answer(part, puzzle.solve_part(&raw_input, part).map_err(|err| with_file(err, &file))?),
+        None => {
+            let answers = puzzle.solve(&raw_input).map_err(|err| with_file(err, &file))?;
+            print_answers(answers.part_1, answers.part_2);
+        }
+    }
+    Ok(())
+}
+
+/// Runs `f`, turning a panic into an error so one broken day is reported like
+/// any other failure instead of ending a run over many days.
+fn catch_panics<T>(f: impl FnOnce() -> Result<T, Box<dyn Error>>) -> Result<T, String> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result.map_err(|err| err.to_string()),
+        Err(payload) => Err(match payload.downcast_ref::<&str>() {
+            Some(message) => format!("panicked: {message}"),
+            None => match payload.downcast_ref::<String>() {
+                Some(message) => format!("panicked: {message}"),
+                None => "panicked".to_string(),
+            },
+        }),
+    }
+}
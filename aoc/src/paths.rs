@@ -0,0 +1,29 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::FILENAME;
+
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+/// The directory holding a day's code and input, e.g. `2025/day_5`.
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    workspace_root().join(year.to_string()).join(format!("day_{day}"))
+}
+
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    day_dir(year, day).join(FILENAME)
+}
+
+pub fn read_input(year: u16, day: u8) -> Result<String, String> {
+    let path = input_path(year, day);
+    fs::read_to_string(&path).map_err(|err| format!("could not read {}: {err}", path.display()))
+}
+
+/// Where a year's known-good answers are kept, e.g. `2025/answers.toml`.
+pub fn answers_path(year: u16) -> PathBuf {
+    workspace_root().join(year.to_string()).join("answers.toml")
+}
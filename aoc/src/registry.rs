@@ -0,0 +1,37 @@
+use aoc_common::Puzzle;
+
+/// Every solved day, in year and day order.
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<aoc_2024_day_7::Day7>(2024, 7),
+    Puzzle::new::<aoc_2025_day_1::Day1>(2025, 1),
+    Puzzle::new::<aoc_2025_day_2::Day2>(2025, 2),
+    Puzzle::new::<aoc_2025_day_3::Day3>(2025, 3),
+    Puzzle::new::<aoc_2025_day_4::Day4>(2025, 4),
+    Puzzle::new::<aoc_2025_day_5::Day5>(2025, 5),
+    Puzzle::new::<aoc_2025_day_6::Day6>(2025, 6),
+    Puzzle::new::<aoc_2025_day_7::Day7>(2025, 7),
+    Puzzle::new::<aoc_2025_day_10::Day10>(2025, 10),
+];
+
+pub fn get(year: u16, day: u8) -> Result<&'static Puzzle, String> {
+    PUZZLES
+        .iter()
+        .find(|p| p.year == year && p.day == day)
+        .ok_or(format!("no solution registered for {year} day {day}"))
+}
+
+pub fn for_year(year: u16) -> impl Iterator<Item = &'static Puzzle> {
+    PUZZLES.iter().filter(move |p| p.year == year)
+}
+
+/// The registered days matching an optional year and day.
+pub fn select(year: Option<u16>, day: Option<u8>) -> Result<Vec<&'static Puzzle>, String> {
+    let puzzles: Vec<&Puzzle> = PUZZLES
+        .iter()
+        .filter(|p| year.is_none_or(|year| p.year == year) && day.is_none_or(|day| p.day == day))
+        .collect();
+    if puzzles.is_empty() {
+        return Err("no registered solutions match".to_string());
+    }
+    Ok(puzzles)
+}
@@ -0,0 +1,188 @@
+use std::{
+    error::Error,
+    fs,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common::FILENAME;
+
+use crate::paths::{day_dir, workspace_root};
+
+const FIRST_YEAR: u16 = 2015;
+/// The first event that ran for 12 days instead of 25.
+const FIRST_SHORT_YEAR: u16 = 2025;
+
+/// Every `<name>.rs` in this directory is a template `aoc new --template <name>`
+/// can start a day from. `{{day_struct}}` is replaced by the day's struct name.
+const TEMPLATES_DIR: &str = "templates";
+pub const DEFAULT_TEMPLATE: &str = "lines";
+const EXAMPLES_DIR: &str = "examples";
+const EXPECTED_ANSWERS_STUB: &str = "\
+# Expected answers for example_1.txt. Leave a part commented out to skip checking it.
+# part_1 = \"\"
+# part_2 = \"\"
+";
+
+pub fn new_day(year: u16, day: u8, template_name: &str) -> Result<(), Box<dyn Error>> {
+    validate(year, day)?;
+    let dir_path = day_dir(year, day);
+    let crate_name = format!("aoc_{year}_day_{day}");
+    let struct_name = format!("Day{day}");
+    let template = read_template(template_name)?;
+    let files = [
+        ("Cargo.toml", cargo_manifest(&crate_name)),
+        ("lib.rs", template.replace("{{day_struct}}", &struct_name)),
+        ("main.rs", main_file(&crate_name, &struct_name)),
+        (FILENAME, String::new()),
+        ("examples/example_1.txt", String::new()),
+        ("examples/example_1.toml", EXPECTED_ANSWERS_STUB.to_string()),
+    ];
+    for (name, _) in &files {
+        let path = dir_path.join(name);
+        if path.exists() {
+            return Err(format!("{} already exists, refusing to overwrite it", path.display()).into());
+        }
+    }
+    // Work out every registration edit up front so a failure leaves the tree untouched.
+    let registrations = registrations(year, day, &crate_name, &struct_name)?;
+
+    fs::create_dir_all(dir_path.join(EXAMPLES_DIR))?;
+    for (name, contents) in files {
+        let path = dir_path.join(name);
+        fs::write(&path, contents)?;
+        println!("Created {}", path.display());
+    }
+    for (path, contents) in registrations {
+        fs::write(&path, contents)?;
+        println!("Registered {year} day {day} in {}", path.display());
+    }
+    Ok(())
+}
+
+fn validate(year: u16, day: u8) -> Result<(), String> {
+    let latest_year = current_year();
+    if !(FIRST_YEAR..=latest_year).contains(&year) {
+        return Err(format!("year must be between {FIRST_YEAR} and {latest_year}, got {year}"));
+    }
+    let num_days = if year >= FIRST_SHORT_YEAR { 12 } else { 25 };
+    if !(1..=num_days).contains(&day) {
+        return Err(format!("{year} has days 1 to {num_days}, got {day}"));
+    }
+    Ok(())
+}
+
+/// Reads the template called `name`, which must be one of
+/// `available_templates`, so a name like `../x` cannot reach other files.
+fn read_template(name: &str) -> Result<String, String> {
+    let available = available_templates();
+    if !available.iter().any(|template| template == name) {
+        return Err(format!("no template named {name}, available templates: {}", available.join(", ")));
+    }
+    let path = workspace_root().join(TEMPLATES_DIR).join(name).with_extension("rs");
+    fs::read_to_string(&path).map_err(|err| format!("could not read {}: {err}", path.display()))
+}
+
+fn available_templates() -> Vec<String> {
+    let Ok(entries) = fs::read_dir(workspace_root().join(TEMPLATES_DIR)) else {
+        return vec![];
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .filter_map(|path| path.file_stem().map(|stem| stem.to_string_lossy().into_owned()))
+        .collect();
+    names.sort();
+    names
+}
+
+fn current_year() -> u16 {
+    const SECONDS_PER_YEAR: u64 = 31_556_952;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+    (1970 + now.as_secs() / SECONDS_PER_YEAR) as u16
+}
+
+fn cargo_manifest(crate_name: &str) -> String {
+    format!(
+        r#"[package]
+name = "{crate_name}"
+version.workspace = true
+edition.workspace = true
+
+[lib]
+path = "lib.rs"
+
+[[bin]]
+name = "{crate_name}"
+path = "main.rs"
+
+[dependencies]
+aoc_common.workspace = true
+"#
+    )
+}
+
+fn main_file(crate_name: &str, struct_name: &str) -> String {
+    format!(
+        r#"use std::process::ExitCode;
+
+use {crate_name}::{struct_name};
+use aoc_common::run;
+
+fn main() -> ExitCode {{
+    run(&{struct_name})
+}}
+"#
+    )
+}
+
+/// The new contents of the workspace manifest, the runner's manifest and its
+/// registry with the day added in year and day order.
+fn registrations(year: u16, day: u8, crate_name: &str, struct_name: &str) -> Result<Vec<(PathBuf, String)>, String> {
+    let root = workspace_root();
+    let entries = [
+        (root.join("Cargo.toml"), format!("    \"{year}/day_{day}\",")),
+        (root.join("aoc/Cargo.toml"), format!("{crate_name} = {{ path = \"../{year}/day_{day}\" }}")),
+        (
+            root.join("aoc/src/registry.rs"),
+            format!("    Puzzle::new::<{crate_name}::{struct_name}>({year}, {day}),"),
+        ),
+    ];
+    entries
+        .into_iter()
+        .map(|(path, new_line)| {
+            let contents = fs::read_to_string(&path).map_err(|err| format!("could not read {}: {err}", path.display()))?;
+            let updated = insert_sorted(&contents, &new_line, (year, day))
+                .map_err(|reason| format!("could not register {year} day {day} in {}: {reason}", path.display()))?;
+            Ok((path, updated))
+        })
+        .collect()
+}
+
+fn insert_sorted(contents: &str, new_line: &str, key: (u16, u8)) -> Result<String, &'static str> {
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let keyed_lines: Vec<(usize, (u16, u8))> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| day_key(line).map(|k| (index, k)))
+        .collect();
+    if keyed_lines.iter().any(|&(_, k)| k == key) {
+        return Err("it is already registered");
+    }
+    let index = match keyed_lines.iter().find(|&&(_, k)| k > key) {
+        Some(&(index, _)) => index,
+        None => keyed_lines.last().ok_or("found no existing days to register it next to")?.0 + 1,
+    };
+    lines.insert(index, new_line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Reads the year and day out of a line mentioning a day, such as
+/// `"2025/day_5",` or `aoc_2025_day_5 = ...`.
+fn day_key(line: &str) -> Option<(u16, u8)> {
+    let (before, after) = line.split_once("day_")?;
+    let year = before.get(before.len().checked_sub(5)?..before.len() - 1)?.parse().ok()?;
+    let day: String = after.chars().take_while(char::is_ascii_digit).collect();
+    Some((year, day.parse().ok()?))
+}
@@ -0,0 +1,81 @@
+//! A stand-in for adventofcode.com that the client tests point `base_url` at.
+
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    sync::{Arc, Mutex, MutexGuard},
+    thread,
+};
+
+use crate::config::Config;
+
+/// One request the stub received.
+pub struct Request {
+    /// Such as `GET /2025/day/1/input`.
+    pub line: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+/// Answers every request with `status` and `body` until the test ends.
+pub struct StubServer {
+    pub base_url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl StubServer {
+    pub fn start(status: u16, body: &str) -> StubServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let received = Arc::clone(&requests);
+        let body = body.to_string();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let request = read_request(&mut BufReader::new(&stream));
+                received.lock().unwrap().push(request);
+                let response = format!(
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        StubServer { base_url, requests }
+    }
+
+    /// A config that logs in to this server.
+    pub fn config(&self) -> Config {
+        Config { session: Some("stub-session".to_string()), base_url: Some(self.base_url.clone()) }
+    }
+
+    pub fn requests(&self) -> MutexGuard<'_, Vec<Request>> {
+        self.requests.lock().unwrap()
+    }
+}
+
+fn read_request(reader: &mut impl BufRead) -> Request {
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    let line = line.trim_end().trim_end_matches(" HTTP/1.1").to_string();
+    let mut cookie = None;
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).unwrap();
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header.split_once(':').unwrap();
+        match name.to_ascii_lowercase().as_str() {
+            "cookie" => cookie = Some(value.trim().to_string()),
+            "content-length" => content_length = value.trim().parse().unwrap(),
+            _ => {}
+        }
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).unwrap();
+    Request { line, cookie, body: String::from_utf8(body).unwrap() }
+}
@@ -0,0 +1,168 @@
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fs,
+    io,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    answers::{AnswerBook, input_hash},
+    client::{Client, Verdict},
+    config::{Config, local_dir},
+    paths::{answers_path, read_input},
+    registry,
+};
+
+/// Every answer submitted for one day, so the same mistake is never sent twice.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct History {
+    #[serde(default)]
+    submissions: Vec<Submission>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Submission {
+    part: u8,
+    answer: String,
+    verdict: Verdict,
+    submitted_at: u64,
+}
+
+pub fn submit(year: u16, day: u8, part: u8) -> Result<(), Box<dyn Error>> {
+    let puzzle = registry::get(year, day)?;
+    let raw_input = read_input(year, day)?;
+    let answer = puzzle.solve_part(&raw_input, part)?;
+    let history_path = history_path(year, day);
+    let mut history = History::load(&history_path)?;
+    if let Some(reason) = history.rule_out(part, &answer) {
+        return Err(format!("not submitting {answer} for part {part}: {reason}").into());
+    }
+
+    let client = Client::new(&Config::load()?)?;
+    println!("Submitting {answer} for {year} day {day} part {part}");
+    let (verdict, message) = client.submit(year, day, part, &answer)?;
+    println!("{message}");
+    history.submissions.push(Submission {
+        part,
+        answer: answer.clone(),
+        verdict,
+        submitted_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs(),
+    });
+    history.save(&history_path)?;
+    if verdict != Verdict::Correct {
+        return Err(format!("answer was not accepted: {verdict}").into());
+    }
+    let mut answer_book = AnswerBook::load(year)?;
+    answer_book.record(day, part, &answer, &input_hash(&raw_input));
+    answer_book.save(year)?;
+    println!("Recorded the answer in {}", answers_path(year).display());
+    Ok(())
+}
+
+fn history_path(year: u16, day: u8) -> PathBuf {
+    local_dir().join("history").join(year.to_string()).join(format!("day_{day}.toml"))
+}
+
+impl History {
+    fn load(path: &Path) -> Result<History, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents).map_err(|err| format!("invalid history {}: {err}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(err) => Err(format!("could not read history {}: {err}", path.display())),
+        }
+    }
+
+    fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Why the history says submitting `answer` would be pointless, if it does.
+    fn rule_out(&self, part: u8, answer: &str) -> Option<String> {
+        let value = answer.parse::<i128>().ok();
+        for submission in self.submissions.iter().filter(|s| s.part == part && s.verdict.is_judgement()) {
+            let previous = &submission.answer;
+            let compared = match (value, previous.parse::<i128>().ok()) {
+                (Some(value), Some(previous_value)) => Some(value.cmp(&previous_value)),
+                _ => None,
+            };
+            let reason = match (submission.verdict, compared) {
+                (Verdict::Correct, _) if previous == answer => Some("it was already accepted".to_string()),
+                (Verdict::Correct, _) => Some(format!("{previous} was already accepted")),
+                (verdict, _) if previous == answer => Some(format!("it was already rejected as {verdict}")),
+                (Verdict::TooHigh, Some(Ordering::Greater)) => Some(format!("{previous} was already too high")),
+                (Verdict::TooLow, Some(Ordering::Less)) => Some(format!("{previous} was already too low")),
+                _ => None,
+            };
+            if reason.is_some() {
+                return reason;
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(submissions: &[(u8, &str, Verdict)]) -> History {
+        let submissions = submissions
+            .iter()
+            .map(|&(part, answer, verdict)| Submission { part, answer: answer.to_string(), verdict, submitted_at: 0 })
+            .collect();
+        History { submissions }
+    }
+
+    #[test]
+    fn rules_out_answers_beyond_a_too_high_or_too_low_one() {
+        let history = history(&[(1, "100", Verdict::TooHigh), (1, "40", Verdict::TooLow)]);
+        assert_eq!(history.rule_out(1, "100").unwrap(), "it was already rejected as too high");
+        assert_eq!(history.rule_out(1, "150").unwrap(), "100 was already too high");
+        assert_eq!(history.rule_out(1, "40").unwrap(), "it was already rejected as too low");
+        assert_eq!(history.rule_out(1, "-3").unwrap(), "40 was already too low");
+        assert_eq!(history.rule_out(1, "99"), None);
+        assert_eq!(history.rule_out(1, "41"), None);
+        // Each part has its own history, and answers that are not numbers
+        // cannot be compared.
+        assert_eq!(history.rule_out(2, "150"), None);
+        assert_eq!(history.rule_out(1, "abc"), None);
+    }
+
+    #[test]
+    fn rules_out_repeats_and_solved_parts() {
+        let history = history(&[
+            (1, "abc", Verdict::Wrong),
+            (1, "7", Verdict::RateLimited),
+            (2, "12", Verdict::Correct),
+        ]);
+        assert_eq!(history.rule_out(1, "abc").unwrap(), "it was already rejected as wrong");
+        // A rate-limited answer was never judged, so it can be sent again.
+        assert_eq!(history.rule_out(1, "7"), None);
+        assert_eq!(history.rule_out(2, "12").unwrap(), "it was already accepted");
+        assert_eq!(history.rule_out(2, "13").unwrap(), "12 was already accepted");
+    }
+
+    #[test]
+    fn only_a_missing_history_is_empty() {
+        let dir = std::env::temp_dir().join(format!("aoc-history-{}", std::process::id()));
+        let path = dir.join("day_1.toml");
+        let _ = fs::remove_dir_all(&dir);
+        assert!(History::load(&path).unwrap().submissions.is_empty());
+
+        history(&[(1, "5", Verdict::TooLow)]).save(&path).unwrap();
+        assert_eq!(History::load(&path).unwrap().rule_out(1, "4").unwrap(), "5 was already too low");
+
+        fs::write(&path, "submissions = 3").unwrap();
+        assert!(History::load(&path).unwrap_err().starts_with("invalid history"));
+        fs::remove_file(&path).unwrap();
+        fs::create_dir(&path).unwrap();
+        assert!(History::load(&path).unwrap_err().starts_with("could not read history"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
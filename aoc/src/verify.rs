@@ -0,0 +1,101 @@
+use std::error::Error;
+
+use aoc_common::Puzzle;
+
+use crate::{
+    answers::{AnswerBook, input_hash},
+    catch_panics,
+    paths::read_input,
+    registry,
+};
+
+enum Status {
+    Pass,
+    Fail,
+    Missing,
+    Error,
+}
+
+struct Row {
+    year: u16,
+    day: u8,
+    part: u8,
+    status: Status,
+    expected: String,
+    actual: String,
+}
+
+/// Runs every selected day and compares its answers with the recorded ones,
+/// failing if any day gives a different answer or cannot be solved.
+pub fn verify(year: Option<u16>, day: Option<u8>) -> Result<(), Box<dyn Error>> {
+    let mut rows = vec![];
+    for puzzle in registry::select(year, day)? {
+        rows.extend(verify_puzzle(puzzle)?);
+    }
+    print_table(&rows);
+
+    let count = |wanted: fn(&Status) -> bool| rows.iter().filter(|row| wanted(&row.status)).count();
+    let failed = count(|s| matches!(s, Status::Fail | Status::Error));
+    println!(
+        "\n{} passed, {failed} failed, {} missing",
+        count(|s| matches!(s, Status::Pass)),
+        count(|s| matches!(s, Status::Missing))
+    );
+    if failed > 0 {
+        return Err(format!("{failed} answer(s) did not match").into());
+    }
+    Ok(())
+}
+
+fn verify_puzzle(puzzle: &Puzzle) -> Result<Vec<Row>, String> {
+    let answer_book = AnswerBook::load(puzzle.year)?;
+    let row = |part, status, expected: Option<&str>, actual: String| Row {
+        year: puzzle.year,
+        day: puzzle.day,
+        part,
+        status,
+        expected: expected.unwrap_or("-").to_string(),
+        actual,
+    };
+    let raw_input = match read_input(puzzle.year, puzzle.day) {
+        Ok(raw_input) => raw_input,
+        Err(err) => return Ok((1..=2).map(|part| row(part, Status::Error, None, err.clone())).collect()),
+    };
+    let known = answer_book.lookup(puzzle.day, &input_hash(&raw_input));
+    let answers = match catch_panics(|| puzzle.solve(&raw_input)) {
+        Ok(answers) => answers,
+        Err(err) => {
+            let expected = |part| known.and_then(|k| k.part(part));
+            return Ok((1..=2).map(|part| row(part, Status::Error, expected(part), err.clone())).collect());
+        }
+    };
+    Ok([(1, answers.part_1), (2, answers.part_2)]
+        .into_iter()
+        .map(|(part, actual)| {
+            let expected = known.and_then(|k| k.part(part));
+            let status = match expected {
+                None => Status::Missing,
+                Some(expected) if expected == actual => Status::Pass,
+                Some(_) => Status::Fail,
+            };
+            row(part, status, expected, actual)
+        })
+        .collect())
+}
+
+fn print_table(rows: &[Row]) {
+    let expected_width = rows.iter().map(|row| row.expected.len()).chain(["Expected".len()]).max().unwrap();
+    println!("Year  Day  Part  Status   {:<expected_width$}  Actual", "Expected");
+    for row in rows {
+        let status = match row.status {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+            Status::Error => "ERROR",
+        };
+        println!(
+            "{:<4}  {:>3}  {:>4}  {:<7}  {:<expected_width$}  {}",
+            row.year, row.day, row.part, status, row.expected, row.actual
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use std::{
+    fmt::{self, Display},
+    ops::{BitXor, BitXorAssign},
+};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A fixed number of bits, packed into `u64` words so XOR works a word at a
+/// time however many bits there are. Bits past `len` in the last word are
+/// always clear, so equal sets compare and hash equal.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    len: usize,
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    /// `len` bits, all clear.
+    pub fn new(len: usize) -> Self {
+        BitSet { len, words: vec![0; len.div_ceil(WORD_BITS)] }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Panics if `index` is not below `len`.
+    pub fn get(&self, index: usize) -> bool {
+        let (word, mask) = self.locate(index);
+        self.words[word] & mask != 0
+    }
+
+    pub fn set(&mut self, index: usize, value: bool) {
+        let (word, mask) = self.locate(index);
+        if value {
+            self.words[word] |= mask;
+        } else {
+            self.words[word] &= !mask;
+        }
+    }
+
+    pub fn toggle(&mut self, index: usize) {
+        let (word, mask) = self.locate(index);
+        self.words[word] ^= mask;
+    }
+
+    /// How many bits are set.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// The indices of the set bits, in order.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len).filter(|&index| self.get(index))
+    }
+
+    fn locate(&self, index: usize) -> (usize, u64) {
+        assert!(index < self.len, "bit {index} is outside the {}-bit set", self.len);
+        (index / WORD_BITS, 1 << (index % WORD_BITS))
+    }
+}
+
+/// Panics if the sets are different lengths.
+impl BitXorAssign<&BitSet> for BitSet {
+    fn bitxor_assign(&mut self, other: &BitSet) {
+        assert_eq!(self.len, other.len, "cannot XOR bit sets of different lengths");
+        self.words.iter_mut().zip(&other.words).for_each(|(word, other_word)| *word ^= other_word);
+    }
+}
+
+impl BitXor<&BitSet> for &BitSet {
+    type Output = BitSet;
+
+    fn bitxor(self, other: &BitSet) -> BitSet {
+        let mut result = self.clone();
+        result ^= other;
+        result
+    }
+}
+
+/// One bit per item, in order.
+impl FromIterator<bool> for BitSet {
+    fn from_iter<I: IntoIterator<Item = bool>>(bits: I) -> Self {
+        let bits: Vec<bool> = bits.into_iter().collect();
+        let mut set = BitSet::new(bits.len());
+        for (index, bit) in bits.into_iter().enumerate() {
+            set.set(index, bit);
+        }
+        set
+    }
+}
+
+/// Prints bit 0 first, as `1` or `0`.
+impl Display for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (0..self.len).try_for_each(|index| write!(f, "{}", if self.get(index) { '1' } else { '0' }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Bits either side of each word boundary in a set spanning three words.
+    const EDGES: [usize; 6] = [0, 63, 64, 127, 128, 129];
+
+    fn with_bits(len: usize, bits: &[usize]) -> BitSet {
+        let mut set = BitSet::new(len);
+        bits.iter().for_each(|&bit| set.set(bit, true));
+        set
+    }
+
+    #[test]
+    fn bits_either_side_of_a_word_boundary_are_independent() {
+        let mut set = with_bits(130, &EDGES);
+        assert_eq!(set.ones().collect::<Vec<_>>(), EDGES);
+        assert_eq!(set.count_ones(), EDGES.len());
+        assert!(!set.get(62) && !set.get(65));
+
+        set.set(64, false);
+        set.toggle(63);
+        set.toggle(65);
+        assert_eq!(set.ones().collect::<Vec<_>>(), [0, 65, 127, 128, 129]);
+    }
+
+    #[test]
+    fn xor_covers_every_word() {
+        let left = with_bits(130, &[1, 63, 64, 129]);
+        let right = with_bits(130, &[63, 64, 65, 128]);
+        assert_eq!((&left ^ &right).ones().collect::<Vec<_>>(), [1, 65, 128, 129]);
+        let mut cleared = left.clone();
+        cleared ^= &left;
+        assert_eq!(cleared, BitSet::new(130));
+        assert_eq!(cleared.count_ones(), 0);
+    }
+
+    #[test]
+    fn sets_with_the_same_bits_are_equal_at_any_length() {
+        let bits: BitSet = (0..65).map(|index| index % 64 == 0).collect();
+        assert_eq!(bits, with_bits(65, &[0, 64]));
+        assert_eq!(bits.len(), 65);
+        assert_eq!(bits.to_string(), format!("1{}1", "0".repeat(63)));
+        assert_ne!(BitSet::new(64), BitSet::new(65));
+        assert!(BitSet::new(0).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "bit 64 is outside the 64-bit set")]
+    fn the_last_word_has_no_spare_bits() {
+        BitSet::new(64).set(64, true);
+    }
+
+    #[test]
+    #[should_panic(expected = "different lengths")]
+    fn xor_needs_equal_lengths() {
+        let _ = &BitSet::new(64) ^ &BitSet::new(65);
+    }
+}
@@ -0,0 +1,241 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::ParseError;
+
+/// A cell's `(row, column)`, counted from the top left.
+pub type Position = (usize, usize);
+
+const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const OFFSETS_8: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid of `fill`. Panics if either is 0, since rows
+    /// and columns are counted off by the width and height, and a grid with
+    /// no cells has neither.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        assert!(width > 0 && height > 0, "a grid needs at least one cell, not {width}x{height}");
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Parses one cell per character, one row per line. `parse_cell` explains
+    /// why a character is not a valid cell, and every row must be as wide as
+    /// the first.
+    pub fn parse(input: &str, mut parse_cell: impl FnMut(char) -> Result<T, String>) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        for line in input.lines() {
+            for (index, c) in line.char_indices() {
+                let cell = parse_cell(c).map_err(|message| ParseError::at(input, &line[index..index + c.len_utf8()], message))?;
+                cells.push(cell);
+            }
+            let line_width = line.chars().count();
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    let message = format!("expected a row of {width} cells like the first, found {line_width}");
+                    return Err(ParseError::at(input, line, message));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid { width, height, cells }),
+            _ => Err(ParseError::at(input, input, "expected a grid, found no cells")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, col): Position) -> Option<&T> {
+        (row < self.height && col < self.width).then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn get_mut(&mut self, (row, col): Position) -> Option<&mut T> {
+        (row < self.height && col < self.width).then(|| &mut self.cells[row * self.width + col])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let (width, height) = (self.width, self.height);
+        (0..height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// The positions above, left, right and below `position` that are on the grid.
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offset_positions(position, &OFFSETS_4)
+    }
+
+    /// Like `neighbors4`, plus the diagonals.
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offset_positions(position, &OFFSETS_8)
+    }
+
+    fn offset_positions(&self, (row, col): Position, offsets: &'static [(isize, isize)]) -> impl Iterator<Item = Position> + '_ {
+        offsets.iter().filter_map(move |&(row_offset, col_offset)| {
+            let neighbor = (row.checked_add_signed(row_offset)?, col.checked_add_signed(col_offset)?);
+            self.get(neighbor).map(|_| neighbor)
+        })
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {col} is outside the {}x{} grid", self.width, self.height);
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// The first position, row by row, holding `value`.
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        let index = self.cells.iter().position(|cell| cell == value)?;
+        Some((index / self.width, index % self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// The grid flipped over its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = self.columns().flat_map(|column| column.cloned()).collect();
+        Grid { width: self.height, height: self.width, cells }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position).unwrap_or_else(|| panic!("{position:?} is outside the {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position).unwrap_or_else(|| panic!("{position:?} is outside the {width}x{height} grid"))
+    }
+}
+
+/// Prints one line per row, with the cells' own `Display` side by side.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ```text
+    /// abc
+    /// def
+    /// ```
+    fn letters() -> Grid<char> {
+        Grid::parse("abc\ndef", Ok).unwrap()
+    }
+
+    fn sorted(positions: impl Iterator<Item = Position>) -> Vec<Position> {
+        let mut positions: Vec<Position> = positions.collect();
+        positions.sort();
+        positions
+    }
+
+    #[test]
+    fn neighbors_stay_on_the_grid() {
+        let grid = letters();
+        assert_eq!(sorted(grid.neighbors4((0, 0))), [(0, 1), (1, 0)]);
+        assert_eq!(sorted(grid.neighbors4((1, 1))), [(0, 1), (1, 0), (1, 2)]);
+        assert_eq!(sorted(grid.neighbors4((1, 2))), [(0, 2), (1, 1)]);
+        assert_eq!(sorted(grid.neighbors8((0, 0))), [(0, 1), (1, 0), (1, 1)]);
+        assert_eq!(sorted(grid.neighbors8((0, 1))), [(0, 0), (0, 2), (1, 0), (1, 1), (1, 2)]);
+        assert_eq!(sorted(grid.neighbors8((1, 2))), [(0, 1), (0, 2), (1, 1)]);
+        assert_eq!(grid.neighbors8((5, 5)).count(), 0);
+        assert_eq!(Grid::new(1, 1, 0).neighbors8((0, 0)).count(), 0);
+    }
+
+    #[test]
+    fn columns_read_down_the_rows() {
+        let grid = letters();
+        assert_eq!(grid.column(0).collect::<String>(), "ad");
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside the 3x2 grid")]
+    fn column_past_the_width_panics() {
+        let _ = letters().column(3);
+    }
+
+    #[test]
+    fn transpose_swaps_rows_and_columns() {
+        let transposed = letters().transpose();
+        assert_eq!((transposed.width(), transposed.height()), (2, 3));
+        assert_eq!(transposed.to_string(), "ad\nbe\ncf");
+        assert_eq!(transposed.transpose(), letters());
+        assert_eq!(Grid::new(4, 1, '.').transpose().to_string(), ".\n.\n.\n.");
+    }
+
+    #[test]
+    fn find_and_rows() {
+        let grid = letters();
+        assert_eq!(grid.find(&'e'), Some((1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [['a', 'b', 'c'], ['d', 'e', 'f']]);
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+    }
+
+    #[test]
+    #[should_panic(expected = "a grid needs at least one cell")]
+    fn new_rejects_a_zero_width() {
+        Grid::new(0, 3, 0);
+    }
+}
@@ -0,0 +1,44 @@
+use std::{
+    fs,
+    io::{self, Read},
+};
+
+use clap::Parser;
+
+/// The puzzle input each day reads when no other path is given.
+pub const FILENAME: &str = "input.txt";
+/// Passed instead of a path to read the input from stdin.
+pub const STDIN_PATH: &str = "-";
+
+// The command line every day's binary accepts.
+#[derive(Parser)]
+#[command(about = "Solves a puzzle input and prints the answers")]
+pub struct InputArgs {
+    /// Puzzle input to solve, or - to read it from stdin
+    #[arg(default_value = FILENAME)]
+    pub input: String,
+    /// Only solve this part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+}
+
+/// Reads a whole input file, or stdin when `path` is `-`.
+pub fn read_input(path: &str) -> Result<String, String> {
+    let result = if path == STDIN_PATH {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map(|_| input)
+    } else {
+        fs::read_to_string(path)
+    };
+    result.map_err(|err| format!("could not read {path}: {err}"))
+}
+
+/// Splits the input into the groups of lines separated by blank lines.
+pub fn split_sections(input: &str) -> Vec<Vec<&str>> {
+    let lines: Vec<&str> = input.lines().collect();
+    lines
+        .split(|line| line.is_empty())
+        .filter(|section| !section.is_empty())
+        .map(|section| section.to_vec())
+        .collect()
+}
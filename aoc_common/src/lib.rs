@@ -0,0 +1,26 @@
+//! Shared helpers for the Advent of Code solutions in this workspace: the
+//! `Solution` trait every day implements, input loading, answer printing,
+//! parsing utilities, and the data structures more than one day has needed:
+//! grids, bit sets, range sets, arithmetic operators and exact linear algebra.
+
+mod bit_set;
+mod grid;
+mod input;
+mod matrix;
+mod operator;
+mod output;
+mod parse_error;
+mod range_set;
+mod rational;
+mod solution;
+
+pub use bit_set::BitSet;
+pub use grid::{Grid, Position};
+pub use input::{FILENAME, InputArgs, STDIN_PATH, read_input, split_sections};
+pub use matrix::{Matrix, Rref, scale_to_integers};
+pub use operator::Operator;
+pub use output::{print_answer, print_answers};
+pub use parse_error::{ParseError, parse_lines, parse_number, with_file};
+pub use range_set::RangeSet;
+pub use rational::{Overflow, Rational, checked_lcm};
+pub use solution::{Answer, Answers, Fallible, Puzzle, Solution, Timings, report, run, run_with_args};
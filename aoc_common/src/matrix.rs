@@ -0,0 +1,213 @@
+use std::{
+    fmt::{self, Display},
+    ops::Index,
+};
+
+use crate::{Overflow, Rational, checked_lcm, rational::gcd};
+
+/// A matrix of exact rationals, stored row by row. Elimination never rounds,
+/// and reports `Overflow` rather than wrapping if an entry outgrows an `i64`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    entries: Vec<Rational>,
+}
+
+impl Matrix {
+    pub fn from_fn(rows: usize, cols: usize, mut entry: impl FnMut(usize, usize) -> Rational) -> Self {
+        let entries = (0..rows).flat_map(|row| (0..cols).map(move |col| (row, col))).map(|(row, col)| entry(row, col)).collect();
+        Matrix { rows, cols, entries }
+    }
+
+    /// Panics if the rows are not all the same length.
+    pub fn from_rows(rows: &[Vec<i64>]) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == cols), "every row of a matrix needs {cols} entries");
+        Matrix::from_fn(rows.len(), cols, |row, col| Rational::from(rows[row][col]))
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, row: usize) -> &[Rational] {
+        &self.entries[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Gauss-Jordan elimination: every pivot is 1 and the only non-zero entry
+    /// in its column, and the pivots step right as they go down.
+    pub fn rref(&self) -> Result<Rref, Overflow> {
+        let mut matrix = self.clone();
+        let mut pivot_cols = vec![];
+        for col in 0..self.cols {
+            let rank = pivot_cols.len();
+            let Some(pivot_row) = (rank..self.rows).find(|&row| !matrix[(row, col)].is_zero()) else {
+                continue;
+            };
+            matrix.swap_rows(rank, pivot_row);
+            let pivot = matrix[(rank, col)];
+            for value in matrix.row_mut(rank) {
+                *value = value.checked_div(pivot)?;
+            }
+            let pivot_values = matrix.row(rank).to_vec();
+            for row in (0..self.rows).filter(|&row| row != rank) {
+                let factor = matrix[(row, col)];
+                if factor.is_zero() {
+                    continue;
+                }
+                for (value, &pivot_value) in matrix.row_mut(row).iter_mut().zip(&pivot_values) {
+                    *value = value.checked_sub(factor.checked_mul(pivot_value)?)?;
+                }
+            }
+            pivot_cols.push(col);
+        }
+        Ok(Rref { matrix, pivot_cols })
+    }
+
+    fn row_mut(&mut self, row: usize) -> &mut [Rational] {
+        &mut self.entries[row * self.cols..(row + 1) * self.cols]
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for col in 0..self.cols {
+            self.entries.swap(a * self.cols + col, b * self.cols + col);
+        }
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = Rational;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Rational {
+        assert!(row < self.rows && col < self.cols, "({row}, {col}) is outside the {}x{} matrix", self.rows, self.cols);
+        &self.entries[row * self.cols + col]
+    }
+}
+
+/// Prints one line per row, with the entries separated by spaces.
+impl Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in 0..self.rows {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            let entries: Vec<String> = self.row(row).iter().map(Rational::to_string).collect();
+            write!(f, "{}", entries.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+/// A matrix in reduced row echelon form, with the column of each row's pivot.
+#[derive(Clone, Debug)]
+pub struct Rref {
+    pub matrix: Matrix,
+    /// `pivot_cols[row]` is where row `row`'s leading 1 is. Rows past the
+    /// rank are all zero.
+    pub pivot_cols: Vec<usize>,
+}
+
+impl Rref {
+    pub fn rank(&self) -> usize {
+        self.pivot_cols.len()
+    }
+
+    /// The columns without a pivot. As variables, these can be chosen freely
+    /// and the pivot variables then follow from them.
+    pub fn free_cols(&self) -> Vec<usize> {
+        (0..self.matrix.cols).filter(|col| !self.pivot_cols.contains(col)).collect()
+    }
+
+    /// A basis of the vectors `x` with `A·x = 0`: one per free column, with a 1
+    /// for that column and 0 for the other free ones.
+    pub fn nullspace(&self) -> Result<Vec<Vec<Rational>>, Overflow> {
+        self.free_cols()
+            .into_iter()
+            .map(|free_col| {
+                let mut vector = vec![Rational::ZERO; self.matrix.cols];
+                vector[free_col] = Rational::ONE;
+                for (row, &pivot_col) in self.pivot_cols.iter().enumerate() {
+                    vector[pivot_col] = self.matrix[(row, free_col)].checked_neg()?;
+                }
+                Ok(vector)
+            })
+            .collect()
+    }
+
+    /// The `nullspace` basis with each vector scaled to the smallest whole numbers.
+    pub fn integer_nullspace(&self) -> Result<Vec<Vec<i64>>, Overflow> {
+        self.nullspace()?.iter().map(|vector| scale_to_integers(vector)).collect()
+    }
+}
+
+/// The smallest whole numbers in the same ratios as `values`, with the same
+/// signs: `[1/2, -1/3]` becomes `[3, -2]`.
+pub fn scale_to_integers(values: &[Rational]) -> Result<Vec<i64>, Overflow> {
+    let multiple = values.iter().try_fold(1, |multiple, value| checked_lcm(multiple, value.denom()))?;
+    let scaled: Vec<i64> = values
+        .iter()
+        .map(|value| value.checked_mul(Rational::from(multiple)).map(Rational::numer))
+        .collect::<Result<_, _>>()?;
+    let divisor = scaled.iter().fold(0, |divisor, value| gcd(divisor, value.unsigned_abs() as u128)).max(1) as i64;
+    Ok(scaled.into_iter().map(|value| value / divisor).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ratio(numer: i64, denom: i64) -> Rational {
+        Rational::new(numer, denom).unwrap()
+    }
+
+    #[test]
+    fn full_rank_reduces_to_the_identity() {
+        let rref = Matrix::from_rows(&[vec![1, 2], vec![3, 4]]).rref().unwrap();
+        assert_eq!(rref.matrix, Matrix::from_rows(&[vec![1, 0], vec![0, 1]]));
+        assert_eq!(rref.rank(), 2);
+        assert_eq!(rref.free_cols(), []);
+        assert_eq!(rref.integer_nullspace(), Ok(vec![]));
+    }
+
+    #[test]
+    fn dependent_rows_leave_free_columns() {
+        let rref = Matrix::from_rows(&[vec![1, 2, 3], vec![2, 4, 6]]).rref().unwrap();
+        assert_eq!(rref.matrix, Matrix::from_rows(&[vec![1, 2, 3], vec![0, 0, 0]]));
+        assert_eq!((rref.rank(), rref.pivot_cols.clone(), rref.free_cols()), (1, vec![0], vec![1, 2]));
+        assert_eq!(rref.integer_nullspace(), Ok(vec![vec![-2, 1, 0], vec![-3, 0, 1]]));
+    }
+
+    #[test]
+    fn elimination_stays_exact() {
+        let rref = Matrix::from_rows(&[vec![2, 3, 1], vec![4, 6, 5]]).rref().unwrap();
+        assert_eq!(rref.pivot_cols, [0, 2]);
+        assert_eq!(rref.matrix.row(0), [Rational::ONE, ratio(3, 2), Rational::ZERO]);
+        assert_eq!(rref.nullspace(), Ok(vec![vec![ratio(-3, 2), Rational::ONE, Rational::ZERO]]));
+        assert_eq!(rref.integer_nullspace(), Ok(vec![vec![-3, 2, 0]]));
+    }
+
+    #[test]
+    fn nullspace_vectors_solve_the_system() {
+        let rows = [vec![1, 1, 0, 1], vec![0, 1, 1, 0], vec![1, 2, 1, 1]];
+        let rref = Matrix::from_rows(&rows).rref().unwrap();
+        assert_eq!(rref.rank(), 2);
+        for vector in rref.integer_nullspace().unwrap() {
+            for row in &rows {
+                assert_eq!(row.iter().zip(&vector).map(|(a, x)| a * x).sum::<i64>(), 0);
+            }
+        }
+    }
+
+    #[test]
+    fn overflow_is_reported_not_wrapped() {
+        let rref = Matrix::from_rows(&[vec![1, i64::MIN]]).rref().unwrap();
+        assert_eq!(rref.nullspace(), Err(Overflow));
+        assert_eq!(rref.integer_nullspace(), Err(Overflow));
+        assert_eq!(Matrix::from_rows(&[vec![3, 1], vec![1, i64::MAX]]).rref().map(|rref| rref.rank()), Err(Overflow));
+    }
+}
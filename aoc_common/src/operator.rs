@@ -0,0 +1,181 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// A binary arithmetic operator on `i64`s. Every operation is checked: one
+/// that would overflow, divide by zero or leave a remainder gives `None`
+/// instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    /// Exact division: `7 / 2` has no answer rather than rounding to 3.
+    Divide,
+    /// Writes the right operand's digits after the left's, so `12 || 345`
+    /// is `12345`. Only defined for non-negative operands.
+    Concatenate,
+}
+
+impl Operator {
+    pub const ALL: [Operator; 5] =
+        [Operator::Add, Operator::Subtract, Operator::Multiply, Operator::Divide, Operator::Concatenate];
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Operator::Add => "+",
+            Operator::Subtract => "-",
+            Operator::Multiply => "*",
+            Operator::Divide => "/",
+            Operator::Concatenate => "||",
+        }
+    }
+
+    pub fn apply(self, left: i64, right: i64) -> Option<i64> {
+        match self {
+            Operator::Add => left.checked_add(right),
+            Operator::Subtract => left.checked_sub(right),
+            Operator::Multiply => left.checked_mul(right),
+            Operator::Divide => exact_div(left, right),
+            Operator::Concatenate if left < 0 || right < 0 => None,
+            Operator::Concatenate => left.checked_mul(digit_shift(right)?)?.checked_add(right),
+        }
+    }
+
+    /// The left operand that makes `left <op> right` come out as `result`, if
+    /// there is one. Working backwards like this is what lets a search prune
+    /// operators that cannot reach a target.
+    pub fn inverse(self, result: i64, right: i64) -> Option<i64> {
+        match self {
+            Operator::Add => result.checked_sub(right),
+            Operator::Subtract => result.checked_add(right),
+            Operator::Multiply => exact_div(result, right),
+            Operator::Divide => result.checked_mul(right).filter(|_| right != 0),
+            Operator::Concatenate if result < 0 || right < 0 => None,
+            Operator::Concatenate => {
+                let shift = digit_shift(right)?;
+                (result % shift == right).then(|| result / shift)
+            }
+        }
+    }
+
+    /// Whether some left operand gives `result` with `right`.
+    pub fn may_apply(self, result: i64, right: i64) -> bool {
+        self.inverse(result, right).is_some()
+    }
+
+    /// Applies the operator left to right across all the operands, so `Add`
+    /// sums them. `None` if there are none or any step has no answer.
+    pub fn fold(self, operands: &[i64]) -> Option<i64> {
+        let (&first, rest) = operands.split_first()?;
+        rest.iter().try_fold(first, |left, &right| self.apply(left, right))
+    }
+}
+
+fn exact_div(dividend: i64, divisor: i64) -> Option<i64> {
+    (dividend.checked_rem(divisor)? == 0).then(|| dividend.checked_div(divisor)).flatten()
+}
+
+/// `10` to the power of how many digits `value` has, e.g. 100 for 42.
+fn digit_shift(value: i64) -> Option<i64> {
+    10_i64.checked_pow(value.checked_ilog10().unwrap_or(0) + 1)
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+impl FromStr for Operator {
+    type Err = String;
+
+    fn from_str(symbol: &str) -> Result<Self, Self::Err> {
+        Operator::ALL.into_iter().find(|operator| operator.symbol() == symbol).ok_or_else(|| {
+            let symbols: Vec<&str> = Operator::ALL.iter().map(|operator| operator.symbol()).collect();
+            format!("invalid operator '{symbol}', expected one of {}", symbols.join(" "))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverse_undoes_apply() {
+        for operator in Operator::ALL {
+            for left in -30..=30 {
+                for right in -30..=30 {
+                    let Some(result) = operator.apply(left, right) else { continue };
+                    // Anything times 0 is 0, so no single left operand gives it back.
+                    let expected = if operator == Operator::Multiply && right == 0 { None } else { Some(left) };
+                    assert_eq!(operator.inverse(result, right), expected, "{left} {operator} {right} = {result}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn division_is_exact_both_ways() {
+        assert_eq!(Operator::Divide.apply(7, 2), None);
+        assert_eq!(Operator::Divide.apply(-8, 2), Some(-4));
+        assert_eq!(Operator::Divide.apply(1, 0), None);
+        assert_eq!(Operator::Divide.apply(i64::MIN, -1), None);
+        // Only 6 / 2 is exactly 3; 7 / 2 would need rounding.
+        assert_eq!(Operator::Divide.inverse(3, 2), Some(6));
+        assert_eq!(Operator::Divide.inverse(3, 0), None);
+        assert_eq!(Operator::Divide.inverse(i64::MAX, 2), None);
+        assert_eq!(Operator::Multiply.inverse(7, 2), None);
+        assert_eq!(Operator::Multiply.inverse(-12, 4), Some(-3));
+    }
+
+    #[test]
+    fn concatenation_strips_the_right_operands_digits() {
+        assert_eq!(Operator::Concatenate.apply(12, 345), Some(12345));
+        assert_eq!(Operator::Concatenate.apply(12, 0), Some(120));
+        assert_eq!(Operator::Concatenate.apply(0, 7), Some(7));
+        assert_eq!(Operator::Concatenate.apply(i64::MAX, 1), None);
+        assert_eq!(Operator::Concatenate.inverse(12345, 345), Some(12));
+        assert_eq!(Operator::Concatenate.inverse(12345, 45), Some(123));
+        assert_eq!(Operator::Concatenate.inverse(12345, 44), None);
+        // 0 still has a digit to strip.
+        assert_eq!(Operator::Concatenate.inverse(120, 0), Some(12));
+        assert_eq!(Operator::Concatenate.inverse(125, 0), None);
+        assert_eq!(Operator::Concatenate.inverse(7, 7), Some(0));
+        for (result, right) in [(-15, 5), (15, -5), (-1, -1)] {
+            assert_eq!(Operator::Concatenate.apply(result, right), None);
+            assert_eq!(Operator::Concatenate.inverse(result, right), None);
+        }
+    }
+
+    #[test]
+    fn may_apply_matches_inverse() {
+        assert!(Operator::Multiply.may_apply(12, 4));
+        assert!(!Operator::Multiply.may_apply(12, 5));
+        assert!(Operator::Concatenate.may_apply(156, 6));
+        assert!(!Operator::Concatenate.may_apply(156, 5));
+        assert!(!Operator::Add.may_apply(i64::MIN, 1));
+        assert!(Operator::Subtract.may_apply(-5, 3));
+    }
+
+    #[test]
+    fn fold_applies_left_to_right() {
+        assert_eq!(Operator::Add.fold(&[1, 2, 3]), Some(6));
+        assert_eq!(Operator::Subtract.fold(&[10, 2, 3]), Some(5));
+        assert_eq!(Operator::Concatenate.fold(&[1, 2, 3]), Some(123));
+        assert_eq!(Operator::Divide.fold(&[12, 2, 4]), None);
+        assert_eq!(Operator::Multiply.fold(&[4]), Some(4));
+        assert_eq!(Operator::Multiply.fold(&[]), None);
+    }
+
+    #[test]
+    fn parses_its_own_symbols() {
+        for operator in Operator::ALL {
+            assert_eq!(operator.to_string().parse(), Ok(operator));
+        }
+        assert_eq!("|".parse::<Operator>(), Err("invalid operator '|', expected one of + - * / ||".to_string()));
+        assert!(" +".parse::<Operator>().is_err());
+    }
+}
@@ -0,0 +1,10 @@
+use std::fmt::Display;
+
+pub fn print_answers(part_1: impl Display, part_2: impl Display) {
+    print_answer(1, part_1);
+    print_answer(2, part_2);
+}
+
+pub fn print_answer(part: u8, answer: impl Display) {
+    println!("Part {} = {}", part, answer);
+}
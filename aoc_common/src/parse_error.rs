@@ -0,0 +1,108 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// Where a puzzle input stopped making sense, rendered with the offending line
+/// and a caret under the bad characters:
+///
+/// ```text
+/// invalid direction 'X'
+///  --> input.txt:3:1
+///   |
+/// 3 | X42
+///   | ^
+/// ```
+#[derive(Debug)]
+pub struct ParseError {
+    pub message: String,
+    /// The file the input came from, once known. Parsers only see text, so
+    /// whoever read the file fills this in with `in_file`.
+    pub file: Option<String>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// How many characters the caret underlines.
+    pub width: usize,
+    /// The whole line the error is on.
+    pub snippet: String,
+}
+
+impl ParseError {
+    /// An error about `span`, which must be a slice of `source` (the whole
+    /// input, or just the line being parsed). A `span` from elsewhere is
+    /// reported at the start of `source`.
+    pub fn at(source: &str, span: &str, message: impl Into<String>) -> Self {
+        let offset = offset_in(source, span).unwrap_or(0);
+        let line_start = source[..offset].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = source[offset..].find('\n').map_or(source.len(), |index| offset + index);
+        ParseError {
+            message: message.into(),
+            file: None,
+            line: source[..offset].matches('\n').count() + 1,
+            column: source[line_start..offset].chars().count() + 1,
+            width: span.chars().count().max(1),
+            snippet: source[line_start..line_end].trim_end_matches('\r').to_string(),
+        }
+    }
+
+    /// An error about a whole line (or anything missing from the end of it).
+    pub fn line(line: &str, message: impl Into<String>) -> Self {
+        ParseError::at(line, line, message)
+    }
+
+    /// Moves an error built from one `line` of `input` to where that line sits
+    /// in the whole input.
+    pub fn within(mut self, input: &str, line: &str) -> Self {
+        if let Some(offset) = offset_in(input, line) {
+            self.line += input[..offset].matches('\n').count();
+        }
+        self
+    }
+
+    pub fn in_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+/// Where `span` starts within `source`, if it is a slice of it.
+fn offset_in(source: &str, span: &str) -> Option<usize> {
+    let start = source.as_ptr() as usize;
+    let span_start = span.as_ptr() as usize;
+    (start..=start + source.len()).contains(&span_start).then(|| span_start - start)
+}
+
+/// Names the file a `Solution::parse` error came from, if it is a `ParseError`.
+pub fn with_file(err: Box<dyn Error>, file: &str) -> Box<dyn Error> {
+    match err.downcast::<ParseError>() {
+        Ok(parse_error) => Box::new(parse_error.in_file(file)),
+        Err(err) => err,
+    }
+}
+
+/// Parses every line of `input` with `parse_line`, whose errors only need to
+/// point into the line they were given.
+pub fn parse_lines<T>(input: &str, mut parse_line: impl FnMut(&str) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    input.lines().map(|line| parse_line(line).map_err(|err| err.within(input, line))).collect()
+}
+
+/// Parses a number, pointing at it if it is not one.
+pub fn parse_number<T: std::str::FromStr>(source: &str, number: &str) -> Result<T, ParseError> {
+    number.parse().map_err(|_| ParseError::at(source, number, format!("expected a number, found '{number}'")))
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let line_number = self.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        writeln!(f, "{}", self.message)?;
+        writeln!(f, "{gutter}--> {}:{}:{}", self.file.as_deref().unwrap_or("<input>"), self.line, self.column)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line_number} | {}", self.snippet)?;
+        write!(f, "{gutter} | {}{}", " ".repeat(self.column - 1), "^".repeat(self.width))
+    }
+}
+
+impl Error for ParseError {}
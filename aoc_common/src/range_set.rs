@@ -0,0 +1,216 @@
+use std::ops::RangeInclusive;
+
+/// A set of integers stored as sorted, disjoint inclusive ranges. Overlapping
+/// and touching ranges are merged as they are inserted, so `3..=5` and `6..=8`
+/// become `3..=8`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RangeSet {
+    /// `(start, end)` pairs, in order, with a gap of at least one between each.
+    ranges: Vec<(i64, i64)>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        RangeSet::default()
+    }
+
+    /// Adds every value in `range`. An empty range adds nothing.
+    pub fn insert(&mut self, range: RangeInclusive<i64>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // The ranges that overlap or touch the new one are a contiguous run.
+        let first = self.ranges.partition_point(|&(_, e)| e.saturating_add(1) < start);
+        let last = self.ranges.partition_point(|&(s, _)| s.saturating_sub(1) <= end);
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    /// Whether `value` is in the set, found by binary search.
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.ranges.partition_point(|&(_, end)| end < value);
+        self.ranges.get(index).is_some_and(|&(start, _)| start <= value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many values are in the set, which is one more than a `u64` holds
+    /// when it covers every `i64`.
+    pub fn cardinality(&self) -> u128 {
+        self.ranges.iter().map(|&(start, end)| u128::from(end.abs_diff(start)) + 1).sum()
+    }
+
+    /// The merged ranges, in order.
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<i64>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// The values missing between the first and last range, as ranges.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<i64>> + '_ {
+        self.ranges.windows(2).map(|pair| pair[0].1 + 1..=pair[1].0 - 1)
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        let mut union = self.clone();
+        for range in other.ranges() {
+            union.insert(range);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    /// The values in this set that are not in `other`.
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let Some(bounds) = self.bounds() else {
+            return RangeSet::new();
+        };
+        self.intersection(&other.complement(bounds))
+    }
+
+    /// The values within `bounds` that are not in this set.
+    pub fn complement(&self, bounds: RangeInclusive<i64>) -> RangeSet {
+        let (min, max) = bounds.into_inner();
+        let mut ranges = vec![];
+        let mut next_start = Some(min);
+        for &(start, end) in &self.ranges {
+            let Some(gap_start) = next_start else { break };
+            if end < gap_start {
+                continue;
+            }
+            if start > max {
+                break;
+            }
+            if start > gap_start {
+                ranges.push((gap_start, start - 1));
+            }
+            next_start = end.checked_add(1);
+        }
+        if let Some(gap_start) = next_start
+            && gap_start <= max
+        {
+            ranges.push((gap_start, max));
+        }
+        RangeSet { ranges }
+    }
+
+    /// The smallest range holding the whole set.
+    fn bounds(&self) -> Option<RangeInclusive<i64>> {
+        Some(self.ranges.first()?.0..=self.ranges.last()?.1)
+    }
+}
+
+impl FromIterator<RangeInclusive<i64>> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<i64>>>(ranges: I) -> Self {
+        let mut set = RangeSet::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i64>]) -> RangeSet {
+        ranges.iter().cloned().collect()
+    }
+
+    fn ranges(set: &RangeSet) -> Vec<RangeInclusive<i64>> {
+        set.ranges().collect()
+    }
+
+    #[test]
+    fn insert_merges_overlapping_and_adjacent_ranges() {
+        assert_eq!(ranges(&set(&[3..=5, 6..=8])), [3..=8]);
+        assert_eq!(ranges(&set(&[10..=20, 1..=4, 15..=25, 6..=9])), [1..=4, 6..=25]);
+        assert_eq!(ranges(&set(&[1..=2, 8..=9, 0..=10])), [0..=10]);
+        assert_eq!(ranges(&set(&[RangeInclusive::new(5, 4)])), []);
+    }
+
+    #[test]
+    fn contains_and_cardinality() {
+        let ids = set(&[3..=5, 10..=14]);
+        assert!(ids.contains(3) && ids.contains(5) && ids.contains(12));
+        assert!(!ids.contains(2) && !ids.contains(6) && !ids.contains(15));
+        assert_eq!(ids.cardinality(), 8);
+        assert_eq!(RangeSet::new().cardinality(), 0);
+        assert_eq!(set(&[i64::MIN..=i64::MAX]).cardinality(), 1 << 64);
+    }
+
+    #[test]
+    fn gaps_lie_between_ranges() {
+        assert_eq!(set(&[1..=2, 5..=5, 7..=9]).gaps().collect::<Vec<_>>(), [3..=4, 6..=6]);
+        assert_eq!(set(&[1..=2]).gaps().count(), 0);
+        assert_eq!(RangeSet::new().gaps().count(), 0);
+    }
+
+    #[test]
+    fn union_and_intersection() {
+        let a = set(&[1..=5, 10..=15]);
+        let b = set(&[4..=11, 20..=20]);
+        assert_eq!(ranges(&a.union(&b)), [1..=15, 20..=20]);
+        assert_eq!(ranges(&a.intersection(&b)), [4..=5, 10..=11]);
+        assert_eq!(ranges(&a.union(&set(&[6..=9]))), [1..=15]);
+        assert_eq!(ranges(&a.intersection(&set(&[6..=9]))), []);
+        assert_eq!(a.union(&RangeSet::new()), a);
+        assert!(a.intersection(&RangeSet::new()).is_empty());
+    }
+
+    #[test]
+    fn difference_removes_the_other_set() {
+        let a = set(&[1..=10, 20..=30]);
+        assert_eq!(ranges(&a.difference(&set(&[3..=4, 8..=22]))), [1..=2, 5..=7, 23..=30]);
+        assert_eq!(ranges(&a.difference(&set(&[-5..=0, 31..=40]))), [1..=10, 20..=30]);
+        assert_eq!(a.difference(&RangeSet::new()), a);
+        assert!(RangeSet::new().difference(&a).is_empty());
+        assert!(a.difference(&set(&[0..=30])).is_empty());
+    }
+
+    #[test]
+    fn complement_within_bounds() {
+        let a = set(&[3..=5, 8..=9]);
+        assert_eq!(ranges(&a.complement(0..=10)), [0..=2, 6..=7, 10..=10]);
+        assert_eq!(ranges(&a.complement(4..=8)), [6..=7]);
+        assert_eq!(ranges(&a.complement(20..=25)), [20..=25]);
+        assert_eq!(ranges(&a.complement(-5..=-1)), [-5..=-1]);
+        assert!(a.complement(8..=9).is_empty());
+        assert_eq!(ranges(&RangeSet::new().complement(1..=3)), [1..=3]);
+        assert!(RangeSet::new().complement(RangeInclusive::new(3, 1)).is_empty());
+    }
+
+    #[test]
+    fn handles_the_ends_of_i64() {
+        let ends = set(&[i64::MIN..=i64::MIN + 1, i64::MAX - 1..=i64::MAX]);
+        assert!(ends.contains(i64::MIN) && ends.contains(i64::MAX) && !ends.contains(0));
+        assert_eq!(ranges(&ends.complement(i64::MIN..=i64::MAX)), [i64::MIN + 2..=i64::MAX - 2]);
+        assert_eq!(ends.gaps().collect::<Vec<_>>(), [i64::MIN + 2..=i64::MAX - 2]);
+        assert_eq!(ranges(&ends.union(&set(&[0..=0]))).len(), 3);
+        assert!(set(&[i64::MIN..=i64::MAX]).complement(i64::MIN..=i64::MAX).is_empty());
+        assert_eq!(ranges(&set(&[i64::MIN..=-1]).union(&set(&[0..=i64::MAX]))), [i64::MIN..=i64::MAX]);
+        assert_eq!(ranges(&set(&[i64::MIN..=i64::MAX]).difference(&set(&[0..=0]))), [i64::MIN..=-1, 1..=i64::MAX]);
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// A result too large for an `i64`. Exact arithmetic reports it instead of
+/// wrapping around to a wrong answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Overflow;
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "arithmetic overflowed an i64")
+    }
+}
+
+impl Error for Overflow {}
+
+/// An exact fraction of two `i64`s, always in lowest terms with a positive
+/// denominator, so equal values compare and hash equal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numer: i64,
+    denom: i64,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { numer: 0, denom: 1 };
+    pub const ONE: Rational = Rational { numer: 1, denom: 1 };
+
+    /// `numer / denom`, or `None` if `denom` is zero.
+    pub fn new(numer: i64, denom: i64) -> Option<Self> {
+        (denom != 0).then(|| Rational::reduced(numer as i128, denom as i128).ok()).flatten()
+    }
+
+    pub fn numer(self) -> i64 {
+        self.numer
+    }
+
+    pub fn denom(self) -> i64 {
+        self.denom
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.numer == 0
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.denom == 1
+    }
+
+    /// The value as an `i64`, if it is a whole number.
+    pub fn to_integer(self) -> Option<i64> {
+        self.is_integer().then_some(self.numer)
+    }
+
+    pub fn checked_add(self, other: Rational) -> Result<Rational, Overflow> {
+        let (a, b, c, d) = self.wide(other);
+        Rational::reduced((a * d).checked_add(c * b).ok_or(Overflow)?, b * d)
+    }
+
+    pub fn checked_sub(self, other: Rational) -> Result<Rational, Overflow> {
+        let (a, b, c, d) = self.wide(other);
+        Rational::reduced((a * d).checked_sub(c * b).ok_or(Overflow)?, b * d)
+    }
+
+    pub fn checked_neg(self) -> Result<Rational, Overflow> {
+        Ok(Rational { numer: self.numer.checked_neg().ok_or(Overflow)?, denom: self.denom })
+    }
+
+    pub fn checked_mul(self, other: Rational) -> Result<Rational, Overflow> {
+        let (a, b, c, d) = self.wide(other);
+        Rational::reduced(a * c, b * d)
+    }
+
+    /// Panics when dividing by zero, like integer division.
+    pub fn checked_div(self, other: Rational) -> Result<Rational, Overflow> {
+        assert!(!other.is_zero(), "attempt to divide by zero");
+        let (a, b, c, d) = self.wide(other);
+        Rational::reduced(a * d, b * c)
+    }
+
+    /// Both operands widened, so the products of any two `i64`s fit.
+    fn wide(self, other: Rational) -> (i128, i128, i128, i128) {
+        (self.numer as i128, self.denom as i128, other.numer as i128, other.denom as i128)
+    }
+
+    fn reduced(numer: i128, denom: i128) -> Result<Rational, Overflow> {
+        let divisor = gcd(numer.unsigned_abs(), denom.unsigned_abs()).max(1) as i128;
+        let sign = denom.signum();
+        let numer = i64::try_from(sign * numer / divisor).map_err(|_| Overflow)?;
+        let denom = i64::try_from(sign * denom / divisor).map_err(|_| Overflow)?;
+        Ok(Rational { numer, denom })
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Rational { numer: value, denom: 1 }
+    }
+}
+
+impl std::ops::Neg for Rational {
+    type Output = Rational;
+
+    /// Panics on a numerator of `i64::MIN`, whatever the build profile.
+    fn neg(self) -> Rational {
+        Rational { numer: self.numer.checked_neg().expect("attempt to negate with overflow"), denom: self.denom }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b, c, d) = self.wide(*other);
+        (a * d).cmp(&(c * b))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_integer() { write!(f, "{}", self.numer) } else { write!(f, "{}/{}", self.numer, self.denom) }
+    }
+}
+
+pub(crate) fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// The least common multiple of two positive `i64`s.
+pub fn checked_lcm(a: i64, b: i64) -> Result<i64, Overflow> {
+    let divisor = gcd(a.unsigned_abs() as u128, b.unsigned_abs() as u128) as i64;
+    (a / divisor).checked_mul(b).ok_or(Overflow)
+}
@@ -0,0 +1,177 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use clap::Parser;
+
+use crate::{InputArgs, STDIN_PATH, print_answer, read_input, with_file};
+
+/// A day's puzzle, split into the stages every solution goes through: the raw
+/// input is parsed once and the parsed form is shared by both parts.
+pub trait Solution {
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>>;
+
+    fn part_1(&self, input: &Self::Input) -> impl Answer;
+
+    fn part_2(&self, input: &Self::Input) -> impl Answer;
+}
+
+/// What a part returns. Any `Display` value is an answer; a part that can fail
+/// on some inputs returns a `Fallible` (or its own type) instead, so a failure
+/// is reported as an error rather than printed as if it were the answer.
+pub trait Answer {
+    fn render(&self) -> Result<String, String>;
+}
+
+impl<T: Display + ?Sized> Answer for T {
+    fn render(&self) -> Result<String, String> {
+        Ok(self.to_string())
+    }
+}
+
+/// A part's answer, or why it has none.
+pub struct Fallible<T>(pub Result<T, String>);
+
+impl<T: Display> Answer for Fallible<T> {
+    fn render(&self) -> Result<String, String> {
+        match &self.0 {
+            Ok(answer) => Ok(answer.to_string()),
+            Err(err) => Err(err.clone()),
+        }
+    }
+}
+
+/// The `main` of every day's binary: solves the input named on the command
+/// line (see `InputArgs`) and prints the answers.
+pub fn run<S: Solution>(solution: &S) -> ExitCode {
+    report(run_with_args(solution, &InputArgs::parse()))
+}
+
+/// Prints a failed run's error for a person to read, rather than the `Debug`
+/// form `main` would print if it returned the error itself.
+pub fn report(result: Result<(), Box<dyn Error>>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Like `run`, for binaries that parse `InputArgs` as part of their own arguments.
+pub fn run_with_args<S: Solution>(solution: &S, args: &InputArgs) -> Result<(), Box<dyn Error>> {
+    let raw_input = read_input(&args.input)?;
+    let file = if args.input == STDIN_PATH { "<stdin>" } else { &args.input };
+    let input = solution.parse(&raw_input).map_err(|err| with_file(err, file))?;
+    let mut failures = vec![];
+    for part in [1, 2].into_iter().filter(|&part| args.part.is_none_or(|selected| selected == part)) {
+        let answer = if part == 1 { solution.part_1(&input).render() } else { solution.part_2(&input).render() };
+        match answer {
+            Ok(answer) => print_answer(part, answer),
+            Err(err) => failures.push(format!("part {part} has no answer: {err}")),
+        }
+    }
+    if !failures.is_empty() {
+        return Err(failures.join("\n").into());
+    }
+    Ok(())
+}
+
+/// Both answers to a puzzle, already rendered for printing or comparison.
+pub struct Answers {
+    pub part_1: String,
+    pub part_2: String,
+}
+
+/// How long each stage of one solve took. Each part's time includes rendering
+/// its answer, since some answers only do their work when rendered.
+#[derive(Clone, Copy)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_1: Duration,
+    pub part_2: Duration,
+}
+
+type Solver = fn(&str) -> Result<(Answers, Timings), Box<dyn Error>>;
+type PartSolver = fn(&str, u8) -> Result<String, Box<dyn Error>>;
+
+/// A solution registered under its year and day, with the solution type erased
+/// so the runner can hold every day in one list.
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    solver: Solver,
+    part_solver: PartSolver,
+}
+
+impl Puzzle {
+    pub const fn new<S: Solution + Default>(year: u16, day: u8) -> Self {
+        Puzzle { year, day, solver: solve_timed::<S>, part_solver: solve_part::<S> }
+    }
+
+    pub fn solve(&self, raw_input: &str) -> Result<Answers, Box<dyn Error>> {
+        self.solve_timed(raw_input).map(|(answers, _)| answers)
+    }
+
+    pub fn solve_timed(&self, raw_input: &str) -> Result<(Answers, Timings), Box<dyn Error>> {
+        (self.solver)(raw_input)
+    }
+
+    /// Solves only part 1 or only part 2, for inputs (like many examples) that
+    /// only make sense for one of them.
+    pub fn solve_part(&self, raw_input: &str, part: u8) -> Result<String, Box<dyn Error>> {
+        (self.part_solver)(raw_input, part)
+    }
+}
+
+fn solve_timed<S: Solution + Default>(raw_input: &str) -> Result<(Answers, Timings), Box<dyn Error>> {
+    let solution = S::default();
+    let start = Instant::now();
+    let input = solution.parse(raw_input)?;
+    let parse = start.elapsed();
+    let start = Instant::now();
+    let part_1 = render_part(1, solution.part_1(&input))?;
+    let part_1_time = start.elapsed();
+    let start = Instant::now();
+    let part_2 = render_part(2, solution.part_2(&input))?;
+    let part_2_time = start.elapsed();
+    Ok((Answers { part_1, part_2 }, Timings { parse, part_1: part_1_time, part_2: part_2_time }))
+}
+
+fn solve_part<S: Solution + Default>(raw_input: &str, part: u8) -> Result<String, Box<dyn Error>> {
+    let solution = S::default();
+    let input = solution.parse(raw_input)?;
+    match part {
+        1 => render_part(1, solution.part_1(&input)),
+        2 => render_part(2, solution.part_2(&input)),
+        _ => Err(format!("there is no part {part}").into()),
+    }
+}
+
+fn render_part(part: u8, answer: impl Answer) -> Result<String, Box<dyn Error>> {
+    answer.render().map_err(|err| format!("part {part} has no answer: {err}").into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn any_display_value_is_an_answer() {
+        assert_eq!(7_u8.render(), Ok("7".to_string()));
+        assert_eq!('x'.render(), Ok("x".to_string()));
+        assert_eq!(format_args!("{}-{}", 1, 2).render(), Ok("1-2".to_string()));
+    }
+
+    #[test]
+    fn a_fallible_part_renders_its_error() {
+        assert_eq!(Fallible(Ok(42)).render(), Ok("42".to_string()));
+        assert_eq!(Fallible::<i64>(Err("overflowed".to_string())).render(), Err("overflowed".to_string()));
+    }
+}
@@ -0,0 +1,27 @@
+use std::error::Error;
+
+use aoc_common::{Answer, Solution};
+
+#[derive(Default)]
+pub struct {{day_struct}};
+
+impl Solution for {{day_struct}} {
+    type Input = Vec<Vec<String>>;
+
+    /// Reads whitespace-separated values and returns them column by column.
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let rows: Vec<Vec<&str>> = input.lines().map(|line| line.split_whitespace().collect()).collect();
+        let num_columns = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        Ok((0..num_columns)
+            .map(|col| rows.iter().filter_map(|row| row.get(col)).map(|v| v.to_string()).collect())
+            .collect())
+    }
+
+    fn part_1(&self, _columns: &Self::Input) -> impl Answer {
+        0
+    }
+
+    fn part_2(&self, _columns: &Self::Input) -> impl Answer {
+        0
+    }
+}
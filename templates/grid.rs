@@ -0,0 +1,22 @@
+use std::error::Error;
+
+use aoc_common::{Answer, Grid, Solution};
+
+#[derive(Default)]
+pub struct {{day_struct}};
+
+impl Solution for {{day_struct}} {
+    type Input = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(Grid::parse(input, Ok)?)
+    }
+
+    fn part_1(&self, _grid: &Self::Input) -> impl Answer {
+        0
+    }
+
+    fn part_2(&self, _grid: &Self::Input) -> impl Answer {
+        0
+    }
+}
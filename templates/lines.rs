@@ -0,0 +1,22 @@
+use std::error::Error;
+
+use aoc_common::{Answer, Solution};
+
+#[derive(Default)]
+pub struct {{day_struct}};
+
+impl Solution for {{day_struct}} {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part_1(&self, _lines: &Self::Input) -> impl Answer {
+        0
+    }
+
+    fn part_2(&self, _lines: &Self::Input) -> impl Answer {
+        0
+    }
+}
@@ -0,0 +1,25 @@
+use std::error::Error;
+
+use aoc_common::{Answer, Solution, split_sections};
+
+#[derive(Default)]
+pub struct {{day_struct}};
+
+impl Solution for {{day_struct}} {
+    type Input = Vec<Vec<String>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(split_sections(input)
+            .into_iter()
+            .map(|section| section.into_iter().map(String::from).collect())
+            .collect())
+    }
+
+    fn part_1(&self, _sections: &Self::Input) -> impl Answer {
+        0
+    }
+
+    fn part_2(&self, _sections: &Self::Input) -> impl Answer {
+        0
+    }
+}